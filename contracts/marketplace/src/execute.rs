@@ -70,9 +70,18 @@ pub fn execute(
     let api = deps.api;
 
     match msg {
-        ExecuteMsg::SetAsk { token_id, seller } => {
-            execute_set_ask(deps, env, info, &token_id, api.addr_validate(&seller)?)
-        }
+        ExecuteMsg::SetAsk {
+            token_id,
+            seller,
+            years,
+        } => execute_set_ask(
+            deps,
+            env,
+            info,
+            &token_id,
+            api.addr_validate(&seller)?,
+            years.unwrap_or(1),
+        ),
         ExecuteMsg::RemoveAsk { token_id } => execute_remove_ask(deps, info, &token_id),
         ExecuteMsg::UpdateAsk { token_id, seller } => {
             execute_update_ask(deps, info, &token_id, api.addr_validate(&seller)?)
@@ -121,6 +130,7 @@ pub fn execute_set_ask(
     info: MessageInfo,
     token_id: &str,
     seller: Addr,
+    years: u32,
 ) -> Result<Response, ContractError> {
     let minter = NAME_MINTER.load(deps.storage)?;
     if info.sender != minter {
@@ -141,7 +151,11 @@ pub fn execute_set_ask(
         return Err(ContractError::NotApproved {});
     }
 
-    let renewal_time = env.block.time.plus_seconds(SECONDS_PER_YEAR);
+    // the minter enforces the maximum term
+    let renewal_time = env
+        .block
+        .time
+        .plus_seconds(SECONDS_PER_YEAR * u64::from(years.max(1)));
 
     let ask = Ask {
         token_id: token_id.to_string(),
@@ -30,7 +30,12 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     /// List name NFT on the marketplace by creating a new ask.
     /// Only the name minter can call this.
-    SetAsk { token_id: TokenId, seller: String },
+    /// The first renewal is due after `years` (1 if not set).
+    SetAsk {
+        token_id: TokenId,
+        seller: String,
+        years: Option<u32>,
+    },
     /// Remove name on the marketplace.
     /// Only the name collection can call this (i.e: when burned).
     RemoveAsk { token_id: TokenId },
@@ -3,12 +3,13 @@ use std::vec;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coin, to_json_binary, Addr, Coin, Decimal, DepsMut, Empty, Env, Event, MessageInfo, Reply,
-    StdError, Uint128, WasmMsg,
+    coin, to_json_binary, Addr, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env, Event,
+    MessageInfo, Order, Reply, StdError, StdResult, Storage, Timestamp, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw721_base::MintMsg;
-use cw_utils::{maybe_addr, must_pay, parse_reply_instantiate_data};
+use cw_storage_plus::Bound;
+use cw_utils::{maybe_addr, must_pay, nonpayable, parse_reply_instantiate_data};
 use name_marketplace::msg::ExecuteMsg as MarketplaceExecuteMsg;
 
 use semver::Version;
@@ -19,16 +20,20 @@ use sg721_name::msg::{
 };
 use sg_name::{Metadata, SgNameExecuteMsg};
 use sg_name_common::{charge_fees, SECONDS_PER_YEAR};
-use sg_name_minter::{Config, SudoParams, PUBLIC_MINT_START_TIME_IN_SECONDS};
+use sg_name_minter::{
+    Config, Reservation, ReservedName, SudoParams, PUBLIC_MINT_START_TIME_IN_SECONDS,
+};
 use sg_std::{Response, SubMsg, NATIVE_DENOM};
 use whitelist_updatable::helpers::WhitelistUpdatableContract;
 use whitelist_updatable_flatrate::helpers::WhitelistUpdatableFlatrateContract;
 
 use crate::error::ContractError;
+use crate::helpers::make_commitment;
 use crate::msg::{ExecuteMsg, InstantiateMsg};
 use crate::state::{
-    WhitelistContract, WhitelistContractType, ADMIN, CONFIG, NAME_COLLECTION, NAME_MARKETPLACE,
-    PAUSED, SUDO_PARAMS, WHITELISTS,
+    CommitRevealParams, Commitment, WhitelistContract, WhitelistContractType, ADMIN, COMMITMENTS,
+    COMMITMENTS_BY_TIME, COMMIT_REVEAL_PARAMS, CONFIG, NAME_COLLECTION, NAME_MARKETPLACE, PAUSED,
+    RESERVED_NAMES, SUDO_PARAMS, WHITELISTS,
 };
 
 // version info for migration info
@@ -38,6 +43,13 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const INIT_COLLECTION_REPLY_ID: u64 = 1;
 const TRADING_START_TIME_OFFSET_IN_SECONDS: u64 = 2 * SECONDS_PER_YEAR;
 
+const DEFAULT_MAX_YEARS: u32 = 10;
+
+const DEFAULT_MIN_COMMITMENT_AGE_IN_SECONDS: u64 = 60;
+const DEFAULT_MAX_COMMITMENT_AGE_IN_SECONDS: u64 = 24 * 60 * 60;
+/// Expired commitments removed with each new commitment
+const COMMITMENT_PRUNE_LIMIT: usize = 10;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     mut deps: DepsMut,
@@ -74,6 +86,8 @@ pub fn instantiate(
         max_name_length: msg.max_name_length,
         base_price: msg.base_price,
         fair_burn_percent: Decimal::percent(msg.fair_burn_bps) / Uint128::from(100u128),
+        max_years: Some(DEFAULT_MAX_YEARS),
+        multi_year_discounts: vec![],
     };
     SUDO_PARAMS.save(deps.storage, &params)?;
 
@@ -82,6 +96,8 @@ pub fn instantiate(
     };
     CONFIG.save(deps.storage, &config)?;
 
+    COMMIT_REVEAL_PARAMS.save(deps.storage, &default_commit_reveal_params())?;
+
     let collection_init_msg = Sg721InstantiateMsg {
         name: "Name Tokens".to_string(),
         symbol: "NAME".to_string(),
@@ -130,7 +146,41 @@ pub fn execute(
     let api = deps.api;
 
     match msg {
-        ExecuteMsg::MintAndList { name } => execute_mint_and_list(deps, info, env, name.trim()),
+        ExecuteMsg::MintAndList {
+            name,
+            years,
+            owner,
+            metadata,
+            associate,
+        } => {
+            let registration = Registration {
+                years: years.unwrap_or(1),
+                owner: maybe_addr(api, owner)?,
+                metadata: metadata.unwrap_or_default(),
+                associate,
+            };
+            execute_mint_and_list(deps, info, env, name.trim(), registration)
+        }
+        ExecuteMsg::BatchMintAndList { names } => {
+            execute_batch_mint_and_list(deps, info, env, names)
+        }
+        ExecuteMsg::CommitMint { commitment } => execute_commit_mint(deps, info, env, commitment),
+        ExecuteMsg::RevealMint {
+            name,
+            salt,
+            years,
+            owner,
+            metadata,
+            associate,
+        } => {
+            let registration = Registration {
+                years: years.unwrap_or(1),
+                owner: maybe_addr(api, owner)?,
+                metadata: metadata.unwrap_or_default(),
+                associate,
+            };
+            execute_reveal_mint(deps, info, env, name.trim(), &salt, registration)
+        }
         ExecuteMsg::UpdateAdmin { admin } => {
             Ok(ADMIN.execute_update_admin(deps, info, maybe_addr(api, admin)?)?)
         }
@@ -141,27 +191,265 @@ pub fn execute(
         } => execute_add_whitelist(deps, info, address, whitelist_type),
         ExecuteMsg::RemoveWhitelist { address } => execute_remove_whitelist(deps, info, address),
         ExecuteMsg::UpdateConfig { config } => execute_update_config(deps, info, env, config),
+        ExecuteMsg::AddReservedNames { names } => execute_add_reserved_names(deps, info, names),
+        ExecuteMsg::RemoveReservedNames { names } => {
+            execute_remove_reserved_names(deps, info, names)
+        }
     }
 }
 
-/// Mint a name for the sender, or `contract` if specified
+/// What is minted besides the name, the sender always pays for it
+pub struct Registration {
+    pub years: u32,
+    /// Defaults to the sender
+    pub owner: Option<Addr>,
+    pub metadata: Metadata,
+    /// Associate the name with its owner
+    pub associate: bool,
+}
+
+impl Registration {
+    fn years(years: u32) -> Self {
+        Registration {
+            years,
+            owner: None,
+            metadata: Metadata::default(),
+            associate: false,
+        }
+    }
+}
+
+/// Mint a name for the sender, or `owner` if specified
 pub fn execute_mint_and_list(
     deps: DepsMut,
     info: MessageInfo,
     env: Env,
     name: &str,
+    registration: Registration,
+) -> Result<Response, ContractError> {
+    if COMMIT_REVEAL_PARAMS.load(deps.storage)?.required {
+        return Err(ContractError::CommitRevealRequired {});
+    }
+
+    mint_and_list(deps, info, env, name, registration)
+}
+
+/// Save a commitment to a name so it can be minted later without being front-run
+pub fn execute_commit_mint(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    commitment: Binary,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let params = COMMIT_REVEAL_PARAMS.load(deps.storage)?;
+    prune_commitments(deps.storage, &env, &params)?;
+
+    // an expired commitment can be made again
+    if let Some(existing) = COMMITMENTS.may_load(deps.storage, &commitment)? {
+        if !is_expired(&existing, &env, &params) {
+            return Err(ContractError::CommitmentExists {});
+        }
+        remove_commitment(deps.storage, &commitment, existing.created_at);
+    }
+
+    let created_at = env.block.time;
+    COMMITMENTS.save(
+        deps.storage,
+        &commitment,
+        &Commitment {
+            owner: info.sender.clone(),
+            created_at,
+        },
+    )?;
+    COMMITMENTS_BY_TIME.save(deps.storage, (created_at.seconds(), &commitment), &Empty {})?;
+
+    let event = Event::new("commit-mint")
+        .add_attribute("owner", info.sender)
+        .add_attribute("commitment", commitment.to_base64());
+    Ok(Response::new().add_event(event))
+}
+
+/// Mint and list a name that the sender committed to at least `min_age` seconds ago
+pub fn execute_reveal_mint(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    name: &str,
+    salt: &str,
+    registration: Registration,
+) -> Result<Response, ContractError> {
+    let params = COMMIT_REVEAL_PARAMS.load(deps.storage)?;
+
+    let owner = registration.owner.as_ref().unwrap_or(&info.sender);
+    let commitment = make_commitment(name, owner.as_str(), salt);
+    let existing = COMMITMENTS
+        .may_load(deps.storage, &commitment)?
+        .ok_or(ContractError::CommitmentNotFound {})?;
+    // only the sender of the commitment can reveal it, a copied reveal doesn't match
+    if existing.owner != info.sender {
+        return Err(ContractError::CommitmentNotOwned {});
+    }
+
+    if is_expired(&existing, &env, &params) {
+        return Err(ContractError::CommitmentExpired {});
+    }
+    let revealable_at = existing.created_at.plus_seconds(params.min_age);
+    if env.block.time < revealable_at {
+        return Err(ContractError::CommitmentTooNew(
+            revealable_at.seconds() - env.block.time.seconds(),
+        ));
+    }
+    remove_commitment(deps.storage, &commitment, existing.created_at);
+
+    mint_and_list(deps, info, env, name, registration)
+}
+
+fn default_commit_reveal_params() -> CommitRevealParams {
+    CommitRevealParams {
+        min_age: DEFAULT_MIN_COMMITMENT_AGE_IN_SECONDS,
+        max_age: DEFAULT_MAX_COMMITMENT_AGE_IN_SECONDS,
+        required: false,
+    }
+}
+
+fn is_expired(commitment: &Commitment, env: &Env, params: &CommitRevealParams) -> bool {
+    env.block.time > commitment.created_at.plus_seconds(params.max_age)
+}
+
+fn remove_commitment(storage: &mut dyn Storage, hash: &[u8], created_at: Timestamp) {
+    COMMITMENTS.remove(storage, hash);
+    COMMITMENTS_BY_TIME.remove(storage, (created_at.seconds(), hash));
+}
+
+// Removes the oldest expired commitments, a few at a time
+fn prune_commitments(
+    storage: &mut dyn Storage,
+    env: &Env,
+    params: &CommitRevealParams,
+) -> StdResult<()> {
+    let Some(cutoff) = env.block.time.seconds().checked_sub(params.max_age) else {
+        return Ok(());
+    };
+
+    let expired = COMMITMENTS_BY_TIME
+        .keys(
+            storage,
+            None,
+            Some(Bound::exclusive((cutoff, &[][..]))),
+            Order::Ascending,
+        )
+        .take(COMMITMENT_PRUNE_LIMIT)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (created_at, hash) in expired {
+        remove_commitment(storage, &hash, Timestamp::from_seconds(created_at));
+    }
+    Ok(())
+}
+
+fn mint_and_list(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    name: &str,
+    registration: Registration,
 ) -> Result<Response, ContractError> {
     if PAUSED.load(deps.storage)? {
         return Err(ContractError::MintingPaused {});
     }
 
-    let whitelists = WHITELISTS.load(deps.storage)?;
-    let sender = &info.sender.to_string();
     let mut res = Response::new();
+    let params = SUDO_PARAMS.load(deps.storage)?;
+
+    let price = add_mint_and_list_msgs(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        name,
+        registration,
+        &mut res,
+    )?;
+    if let Some(price) = validate_payment_amount(&info, price)? {
+        charge_fees(&mut res, params.fair_burn_percent, price.amount);
+    }
+
+    Ok(res)
+}
+
+/// Mint several names for the sender, paying for all of them at once
+pub fn execute_batch_mint_and_list(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    names: Vec<String>,
+) -> Result<Response, ContractError> {
+    if PAUSED.load(deps.storage)? {
+        return Err(ContractError::MintingPaused {});
+    }
+    if COMMIT_REVEAL_PARAMS.load(deps.storage)?.required {
+        return Err(ContractError::CommitRevealRequired {});
+    }
+    if names.is_empty() {
+        return Err(ContractError::EmptyBatch {});
+    }
+
+    let mut res = Response::new();
+    let params = SUDO_PARAMS.load(deps.storage)?;
+
+    // a taken name fails its mint message and with it the whole batch
+    let mut total = Uint128::zero();
+    for name in names.iter().map(|name| name.trim()) {
+        total += add_mint_and_list_msgs(
+            deps.as_ref(),
+            &env,
+            &info.sender,
+            name,
+            Registration::years(1),
+            &mut res,
+        )?;
+    }
+    if let Some(price) = validate_payment_amount(&info, total)? {
+        charge_fees(&mut res, params.fair_burn_percent, price.amount);
+    }
+
+    let event = Event::new("batch-mint-and-list")
+        .add_attribute("owner", info.sender)
+        .add_attribute("count", names.len().to_string())
+        .add_attribute("price", total.to_string());
+    Ok(res.add_event(event))
+}
+
+// Adds the messages minting and listing a name, returns the price to pay for it
+fn add_mint_and_list_msgs(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    name: &str,
+    registration: Registration,
+    res: &mut Response,
+) -> Result<Uint128, ContractError> {
+    let whitelists = WHITELISTS.load(deps.storage)?;
+    let sender = &sender.to_string();
     let config = CONFIG.load(deps.storage)?;
 
     let params = SUDO_PARAMS.load(deps.storage)?;
     validate_name(name, params.min_name_length, params.max_name_length)?;
+    let years = registration.years;
+    let term_price = term_price(&params, years)?;
+    let reservation = RESERVED_NAMES.may_load(deps.storage, name)?;
+    if let Some(reservation) = &reservation {
+        if reservation.owner.as_ref().map(Addr::as_str) != Some(sender.as_str()) {
+            return Err(ContractError::NameReserved {});
+        }
+    }
+    let owner = registration
+        .owner
+        .map_or_else(|| sender.to_string(), |owner| owner.to_string());
+    if registration.associate && owner != *sender {
+        return Err(ContractError::AssociateNotOwner {});
+    }
 
     // Assumes no duplicate addresses between whitelists
     // Otherwise there will be edge cases with per addr limit between the whitelists
@@ -188,6 +476,7 @@ pub fn execute_mint_and_list(
         return Err(ContractError::MintingNotStarted {});
     }
 
+    // each processed mint counts towards the per address limit of the whitelist
     if let Some(list) = list {
         match list.contract_type {
             WhitelistContractType::UpdatableFlatrateDiscount => {
@@ -224,23 +513,23 @@ pub fn execute_mint_and_list(
         }
     });
 
-    let price = validate_payment(name.len(), &info, params.base_price.u128(), discount)?;
-    if price.is_some() {
-        charge_fees(
-            &mut res,
-            params.fair_burn_percent,
-            price.clone().unwrap().amount,
-        );
-    }
+    // reserved names are minted at their own price
+    let price = match reservation.and_then(|reservation| reservation.price) {
+        Some(price) => price
+            .checked_mul(Uint128::from(years))
+            .map_err(StdError::from)?,
+        None => mint_price(name.len(), term_price.u128(), discount)?,
+    };
 
     let collection = NAME_COLLECTION.load(deps.storage)?;
     let marketplace = NAME_MARKETPLACE.load(deps.storage)?;
 
+    // the collection validates the metadata and associates `token_uri` with the name
     let mint_msg = NameCollectionExecuteMsg::Mint(MintMsg::<Metadata> {
         token_id: name.to_string(),
-        owner: sender.to_string(),
-        token_uri: None,
-        extension: Metadata::default(),
+        owner: owner.clone(),
+        token_uri: registration.associate.then(|| owner.clone()),
+        extension: registration.metadata,
     });
     let mint_msg_exec = WasmMsg::Execute {
         contract_addr: collection.to_string(),
@@ -250,7 +539,8 @@ pub fn execute_mint_and_list(
 
     let ask_msg = MarketplaceExecuteMsg::SetAsk {
         token_id: name.to_string(),
-        seller: sender.to_string(),
+        seller: owner.clone(),
+        years: Some(years),
     };
     let list_msg_exec = WasmMsg::Execute {
         contract_addr: marketplace.to_string(),
@@ -260,18 +550,15 @@ pub fn execute_mint_and_list(
 
     let event = Event::new("mint-and-list")
         .add_attribute("name", name)
-        .add_attribute("owner", sender)
-        .add_attribute(
-            "price",
-            price
-                .unwrap_or_else(|| coin(0, NATIVE_DENOM))
-                .amount
-                .to_string(),
-        );
-    Ok(res
-        .add_event(event)
-        .add_message(mint_msg_exec)
-        .add_message(list_msg_exec))
+        .add_attribute("owner", owner)
+        .add_attribute("sender", sender)
+        .add_attribute("years", years.to_string())
+        .add_attribute("price", price.to_string());
+    res.events.push(event);
+    res.messages.push(SubMsg::new(mint_msg_exec));
+    res.messages.push(SubMsg::new(list_msg_exec));
+
+    Ok(price)
 }
 
 /// Pause or unpause minting
@@ -364,6 +651,57 @@ pub fn execute_update_config(
     Ok(Response::new().add_event(event))
 }
 
+pub fn execute_add_reserved_names(
+    deps: DepsMut,
+    info: MessageInfo,
+    names: Vec<ReservedName>,
+) -> Result<Response, ContractError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+
+    let event = add_reserved_names(deps, names)?;
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_remove_reserved_names(
+    deps: DepsMut,
+    info: MessageInfo,
+    names: Vec<String>,
+) -> Result<Response, ContractError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+
+    let event = remove_reserved_names(deps, names);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn add_reserved_names(deps: DepsMut, names: Vec<ReservedName>) -> Result<Event, ContractError> {
+    let params = SUDO_PARAMS.load(deps.storage)?;
+
+    let mut added = vec![];
+    for reserved in names {
+        validate_name(
+            &reserved.name,
+            params.min_name_length,
+            params.max_name_length,
+        )?;
+        let reservation = Reservation {
+            owner: maybe_addr(deps.api, reserved.owner)?,
+            price: reserved.price,
+        };
+        RESERVED_NAMES.save(deps.storage, &reserved.name, &reservation)?;
+        added.push(reserved.name);
+    }
+
+    Ok(Event::new("add-reserved-names").add_attribute("names", added.join(",")))
+}
+
+pub fn remove_reserved_names(deps: DepsMut, names: Vec<String>) -> Event {
+    for name in &names {
+        RESERVED_NAMES.remove(deps.storage, name);
+    }
+
+    Event::new("remove-reserved-names").add_attribute("names", names.join(","))
+}
+
 // This follows the same rules as Internet domain names
 fn validate_name(name: &str, min: u32, max: u32) -> Result<(), ContractError> {
     let len = name.len() as u32;
@@ -389,17 +727,40 @@ fn validate_name(name: &str, min: u32, max: u32) -> Result<(), ContractError> {
     Ok(())
 }
 
+// Base price for the whole term, before the name length and whitelist pricing
+fn term_price(params: &SudoParams, years: u32) -> Result<Uint128, ContractError> {
+    let max = params.max_years.unwrap_or(1);
+    if years == 0 {
+        return Err(ContractError::InvalidYears {});
+    } else if years > max {
+        return Err(ContractError::TooManyYears { max });
+    }
+
+    let discount = params
+        .multi_year_discounts
+        .iter()
+        .filter(|d| d.min_years <= years)
+        .max_by_key(|d| d.min_years)
+        .map_or(Decimal::zero(), |d| d.discount);
+
+    let price = params
+        .base_price
+        .checked_mul(Uint128::from(years))
+        .map_err(StdError::from)?;
+
+    Ok(price * (Decimal::one() - discount))
+}
+
 pub enum Discount {
     Flatrate(u64),
     Percent(Decimal),
 }
 
-fn validate_payment(
+fn mint_price(
     name_len: usize,
-    info: &MessageInfo,
     base_price: u128,
     discount: Option<Discount>,
-) -> Result<Option<Coin>, ContractError> {
+) -> Result<Uint128, ContractError> {
     // Because we know we are left with ASCII chars, a simple byte count is enough
     let mut amount: Uint128 = (match name_len {
         0..=2 => {
@@ -426,6 +787,13 @@ fn validate_payment(
         None => {}
     }
 
+    Ok(amount)
+}
+
+fn validate_payment_amount(
+    info: &MessageInfo,
+    amount: Uint128,
+) -> Result<Option<Coin>, ContractError> {
     if amount.is_zero() {
         return Ok(None);
     }
@@ -499,6 +867,10 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
         return Ok(Response::new());
     }
 
+    if COMMIT_REVEAL_PARAMS.may_load(deps.storage)?.is_none() {
+        COMMIT_REVEAL_PARAMS.save(deps.storage, &default_commit_reveal_params())?;
+    }
+
     // set new contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::new())
@@ -506,11 +878,31 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{coin, Addr, MessageInfo};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{coin, Addr, Empty, MessageInfo};
+    use cw2::set_contract_version;
 
     use crate::contract::{self, validate_name};
+    use crate::state::COMMIT_REVEAL_PARAMS;
 
-    use super::validate_payment;
+    use super::{
+        default_commit_reveal_params, migrate, mint_price, validate_payment_amount, CONTRACT_NAME,
+        CONTRACT_VERSION,
+    };
+
+    #[test]
+    fn migrate_commit_reveal_params() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "2.3.0").unwrap();
+
+        migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+        let params = COMMIT_REVEAL_PARAMS.load(deps.as_ref().storage).unwrap();
+        assert_eq!(params, default_commit_reveal_params());
+        // existing minting keeps working until sudo requires commitments
+        assert!(!params.required);
+        let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+        assert_eq!(version.version, CONTRACT_VERSION);
+    }
 
     #[test]
     fn check_validate_name() {
@@ -553,7 +945,7 @@ mod tests {
             funds: vec![coin(base_price, "ustars")],
         };
         assert_eq!(
-            validate_payment(5, &info, base_price, None)
+            validate_payment_amount(&info, mint_price(5, base_price, None).unwrap())
                 .unwrap()
                 .unwrap()
                 .amount
@@ -566,7 +958,7 @@ mod tests {
             funds: vec![coin(base_price * 10, "ustars")],
         };
         assert_eq!(
-            validate_payment(4, &info, base_price, None)
+            validate_payment_amount(&info, mint_price(4, base_price, None).unwrap())
                 .unwrap()
                 .unwrap()
                 .amount
@@ -579,7 +971,7 @@ mod tests {
             funds: vec![coin(base_price * 100, "ustars")],
         };
         assert_eq!(
-            validate_payment(3, &info, base_price, None)
+            validate_payment_amount(&info, mint_price(3, base_price, None).unwrap())
                 .unwrap()
                 .unwrap()
                 .amount
@@ -598,11 +990,9 @@ mod tests {
         };
         assert_eq!(
             // we treat the discount as a flat amount given as 100.0
-            validate_payment(
-                5,
+            validate_payment_amount(
                 &info,
-                base_price,
-                Some(contract::Discount::Flatrate(100)),
+                mint_price(5, base_price, Some(contract::Discount::Flatrate(100))).unwrap(),
             )
             .unwrap()
             .unwrap()
@@ -46,4 +46,43 @@ pub enum ContractError {
 
     #[error("Invalid Whitelist Type")]
     InvalidWhitelistType {},
+
+    #[error("Name is reserved")]
+    NameReserved {},
+
+    #[error("No names to mint")]
+    EmptyBatch {},
+
+    #[error("Names must be registered for at least a year")]
+    InvalidYears {},
+
+    #[error("Names can be registered for at most {max} years")]
+    TooManyYears { max: u32 },
+
+    #[error("Multi-year discounts must be below 100%")]
+    InvalidMultiYearDiscount {},
+
+    #[error("Only names minted for the sender can be associated")]
+    AssociateNotOwner {},
+
+    #[error("Names must be minted with a commitment")]
+    CommitRevealRequired {},
+
+    #[error("Commitment already exists")]
+    CommitmentExists {},
+
+    #[error("Commitment not found")]
+    CommitmentNotFound {},
+
+    #[error("Commitment was made by another sender")]
+    CommitmentNotOwned {},
+
+    #[error("Commitment can be revealed in {0} seconds")]
+    CommitmentTooNew(u64),
+
+    #[error("Commitment expired")]
+    CommitmentExpired {},
+
+    #[error("Invalid commit-reveal params: min age must be lower than max age")]
+    InvalidCommitRevealParams {},
 }
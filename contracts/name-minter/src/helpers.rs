@@ -1,12 +1,19 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, Addr, QuerierWrapper, QueryRequest, StdResult, WasmMsg, WasmQuery,
+    to_json_binary, Addr, Binary, QuerierWrapper, QueryRequest, StdResult, WasmMsg, WasmQuery,
 };
 use sg_name_minter::SudoParams;
 use sg_std::CosmosMsg;
+use sha2::{Digest, Sha256};
 
 use crate::msg::{ExecuteMsg, QueryMsg};
 
+/// Hash committed to with `CommitMint` before revealing `name` with `RevealMint`.
+/// Names and addresses can't contain `:` so the preimage is unambiguous.
+pub fn make_commitment(name: &str, owner: &str, salt: &str) -> Binary {
+    Binary::from(&Sha256::digest(format!("{}:{}:{}", name, owner, salt))[..])
+}
+
 /// NameMinterContract is a wrapper around Addr that provides a lot of helpers
 #[cw_serde]
 pub struct NameMinterContract(pub Addr);
@@ -32,7 +32,9 @@ use whitelist_updatable_flatrate::msg::{
 };
 
 pub fn contract_minter() -> Box<dyn Contract<StargazeMsgWrapper>> {
-    let contract = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let contract = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(crate::sudo::sudo);
     Box::new(contract)
 }
 
@@ -301,6 +303,10 @@ fn mint_and_list(
 
     let msg = ExecuteMsg::MintAndList {
         name: name.to_string(),
+        years: None,
+        owner: None,
+        metadata: None,
+        associate: false,
     };
 
     app.execute_contract(
@@ -353,9 +359,12 @@ mod execute {
     use cw721::{NftInfoResponse, OperatorsResponse};
     use name_marketplace::state::{Ask, SudoParams};
     use sg721_name::msg::QueryMsg as Sg721NameQueryMsg;
-    use sg_name::Metadata;
+    use sg_name::{Metadata, TextRecord};
+    use sg_name_minter::MultiYearDiscount;
     use whitelist_updatable_flatrate::msg::QueryMsg::IncludesAddress;
 
+    use crate::ContractError;
+
     use crate::msg::QueryMsg;
 
     use super::*;
@@ -802,6 +811,283 @@ mod execute {
         assert_eq!(params.min_price, Uint128::from(1000u128));
         assert_eq!(params.ask_interval, 1000);
     }
+
+    #[test]
+    fn mint_multiple_years() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let msg = crate::msg::SudoMsg::UpdateParams {
+            min_name_length: 3,
+            max_name_length: 63,
+            base_price: Uint128::from(BASE_PRICE),
+            fair_burn_bps: 5000,
+            max_years: Some(3),
+            multi_year_discounts: Some(vec![MultiYearDiscount {
+                min_years: 2,
+                discount: Decimal::percent(10),
+            }]),
+        };
+        app.wasm_sudo(Addr::unchecked(MINTER), &msg).unwrap();
+
+        // updating other params keeps the multi-year ones
+        let msg = crate::msg::SudoMsg::UpdateParams {
+            min_name_length: 3,
+            max_name_length: 63,
+            base_price: Uint128::from(BASE_PRICE),
+            fair_burn_bps: 5000,
+            max_years: None,
+            multi_year_discounts: None,
+        };
+        app.wasm_sudo(Addr::unchecked(MINTER), &msg).unwrap();
+        let params: NameMinterParams = app
+            .wrap()
+            .query_wasm_smart(MINTER, &SgNameMinterQueryMsg::Params {})
+            .unwrap();
+        assert_eq!(params.max_years, Some(3));
+        assert_eq!(params.multi_year_discounts.len(), 1);
+
+        let approve_all_msg = Sg721NameExecuteMsg::ApproveAll {
+            operator: MKT.to_string(),
+            expires: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &approve_all_msg,
+            &[],
+        )
+        .unwrap();
+
+        // 3 years at 10% off
+        let price = coins(BASE_PRICE * 3 * 9 / 10, NATIVE_DENOM);
+        app.sudo(CwSudoMsg::Bank(BankSudo::Mint {
+            to_address: USER2.to_string(),
+            amount: price.clone(),
+        }))
+        .unwrap();
+
+        let mint = |years| ExecuteMsg::MintAndList {
+            name: NAME2.to_string(),
+            years: Some(years),
+            owner: None,
+            metadata: None,
+            associate: false,
+        };
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER2),
+                Addr::unchecked(MINTER),
+                &mint(4),
+                &price,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::TooManyYears { max: 3 }.to_string()
+        );
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER2),
+                Addr::unchecked(MINTER),
+                &mint(0),
+                &price,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidYears {}.to_string()
+        );
+        app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(MINTER),
+            &mint(3),
+            &price,
+        )
+        .unwrap();
+
+        let msg = MarketplaceQueryMsg::Ask {
+            token_id: NAME2.to_string(),
+        };
+        let ask: Option<Ask> = app.wrap().query_wasm_smart(MKT, &msg).unwrap();
+        assert_eq!(
+            ask.unwrap().renewal_time,
+            app.block_info().time.plus_seconds(3 * SECONDS_PER_YEAR)
+        );
+    }
+
+    #[test]
+    fn mint_for_recipient_with_metadata() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        // the recipient approves the marketplace, the sender pays
+        let approve_all_msg = Sg721NameExecuteMsg::ApproveAll {
+            operator: MKT.to_string(),
+            expires: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(USER3),
+            Addr::unchecked(COLLECTION),
+            &approve_all_msg,
+            &[],
+        )
+        .unwrap();
+        let price = coins(BASE_PRICE, NATIVE_DENOM);
+        app.sudo(CwSudoMsg::Bank(BankSudo::Mint {
+            to_address: USER2.to_string(),
+            amount: price.clone(),
+        }))
+        .unwrap();
+
+        let mint = |owner: &str, records: Vec<TextRecord>, associate| ExecuteMsg::MintAndList {
+            name: NAME2.to_string(),
+            years: None,
+            owner: Some(owner.to_string()),
+            metadata: Some(Metadata {
+                records,
+                ..Metadata::default()
+            }),
+            associate,
+        };
+
+        let records = vec![
+            TextRecord::new("twitter", "user3"),
+            TextRecord::new("twitter", "other"),
+        ];
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER2),
+                Addr::unchecked(MINTER),
+                &mint(USER3, records, false),
+                &price,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            sg721_name::ContractError::RecordNameAlreadyExists {}.to_string()
+        );
+
+        // names can't be listed for a recipient that didn't approve the marketplace
+        let records = vec![TextRecord::new("twitter", "user3")];
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(MINTER),
+            &mint(BIDDER, records.clone(), false),
+            &price,
+        );
+        assert!(res.is_err());
+
+        // a gift can't replace the name the recipient is associated with
+        mint_and_list(&mut app, NAME, USER3, None).unwrap();
+        let msg = SgNameExecuteMsg::AssociateAddress {
+            name: NAME.to_string(),
+            address: Some(USER3.to_string()),
+        };
+        app.execute_contract(
+            Addr::unchecked(USER3),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        )
+        .unwrap();
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER2),
+                Addr::unchecked(MINTER),
+                &mint(USER3, records.clone(), true),
+                &price,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::AssociateNotOwner {}.to_string()
+        );
+
+        app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(MINTER),
+            &mint(USER3, records, false),
+            &price,
+        )
+        .unwrap();
+        assert_eq!(owner_of(&app, NAME2.to_string()), USER3);
+
+        let address: String = app
+            .wrap()
+            .query_wasm_smart(
+                COLLECTION,
+                &SgNameQueryMsg::AssociatedAddress {
+                    name: NAME.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(address, USER3);
+        let record: Option<TextRecord> = app
+            .wrap()
+            .query_wasm_smart(
+                COLLECTION,
+                &Sg721NameQueryMsg::TextRecord {
+                    name: NAME2.to_string(),
+                    record_name: "twitter".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(record, Some(TextRecord::new("twitter", "user3")));
+
+        let msg = MarketplaceQueryMsg::Ask {
+            token_id: NAME2.to_string(),
+        };
+        let ask: Option<Ask> = app.wrap().query_wasm_smart(MKT, &msg).unwrap();
+        assert_eq!(ask.unwrap().seller, USER3);
+    }
+
+    #[test]
+    fn mint_and_associate() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let approve_all_msg = Sg721NameExecuteMsg::ApproveAll {
+            operator: MKT.to_string(),
+            expires: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &approve_all_msg,
+            &[],
+        )
+        .unwrap();
+        let price = coins(BASE_PRICE, NATIVE_DENOM);
+        app.sudo(CwSudoMsg::Bank(BankSudo::Mint {
+            to_address: USER2.to_string(),
+            amount: price.clone(),
+        }))
+        .unwrap();
+
+        let msg = ExecuteMsg::MintAndList {
+            name: NAME2.to_string(),
+            years: None,
+            owner: Some(USER2.to_string()),
+            metadata: None,
+            associate: true,
+        };
+        app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(MINTER),
+            &msg,
+            &price,
+        )
+        .unwrap();
+
+        let address: String = app
+            .wrap()
+            .query_wasm_smart(
+                COLLECTION,
+                &SgNameQueryMsg::AssociatedAddress {
+                    name: NAME2.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(address, USER2);
+    }
 }
 
 mod admin {
@@ -1689,11 +1975,17 @@ mod query {
 }
 
 mod collection {
-    use cosmwasm_std::{to_json_binary, StdResult};
+    use cosmwasm_std::{to_json_binary, Empty, Event, StdResult};
     use cw721::NftInfoResponse;
-    use cw_controllers::AdminResponse;
+    use cw721_base::MintMsg;
     use name_marketplace::state::Ask;
-    use sg721_name::{msg::QueryMsg as Sg721NameQueryMsg, state::SudoParams};
+    use sg721_name::{
+        msg::{
+            ImageNftStatusResponse, ManagerResponse, QueryMsg as Sg721NameQueryMsg,
+            VerifierResponse,
+        },
+        state::{SudoParams, CANNOT_SET_ADDRESS, CANNOT_TRANSFER},
+    };
     use sg_name::{Metadata, TextRecord, NFT};
 
     use super::*;
@@ -1798,9 +2090,16 @@ mod collection {
         );
         assert!(res.is_ok());
 
-        let msg = Sg721NameQueryMsg::Verifier {};
-        let verifier: AdminResponse = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
-        assert_eq!(verifier.admin, Some(VERIFIER.to_string()));
+        let msg = Sg721NameQueryMsg::Verifiers {};
+        let verifiers: Vec<VerifierResponse> =
+            app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(
+            verifiers,
+            vec![VerifierResponse {
+                verifier: Addr::unchecked(VERIFIER),
+                scope: "*".to_string(),
+            }]
+        );
 
         // query text record to see if verified is set
         let res: NftInfoResponse<Metadata> = app
@@ -1817,18 +2116,15 @@ mod collection {
     }
 
     #[test]
-    fn verify_false() {
+    fn verification_expires() {
         let mut app = instantiate_contracts(None, None, None);
 
         let res = mint_and_list(&mut app, NAME, USER, None);
         assert!(res.is_ok());
 
-        let name = "twitter";
-        let value = "shan3v";
-
         let msg = SgNameExecuteMsg::AddTextRecord {
             name: NAME.to_string(),
-            record: TextRecord::new(name, value),
+            record: TextRecord::new("twitter", "shan3v"),
         };
         let res = app.execute_contract(
             Addr::unchecked(USER),
@@ -1838,11 +2134,16 @@ mod collection {
         );
         assert!(res.is_ok());
 
-        // verify something as false
+        let msg = sg721_name::msg::SudoMsg::UpdateVerificationPeriod {
+            period: Some(SECONDS_PER_YEAR),
+        };
+        let res = app.wasm_sudo(Addr::unchecked(COLLECTION), &msg);
+        assert!(res.is_ok());
+
         let msg = SgNameExecuteMsg::VerifyTextRecord {
             name: NAME.to_string(),
-            record_name: name.to_string(),
-            result: false,
+            record_name: "twitter".to_string(),
+            result: true,
         };
         let res = app.execute_contract(
             Addr::unchecked(VERIFIER),
@@ -1852,84 +2153,433 @@ mod collection {
         );
         assert!(res.is_ok());
 
-        // query text record to see if verified is set
-        let res: NftInfoResponse<Metadata> = app
-            .wrap()
-            .query_wasm_smart(
-                COLLECTION,
-                &(Sg721NameQueryMsg::NftInfo {
-                    token_id: NAME.to_string(),
-                }),
+        let msg = SgNameQueryMsg::IsVerified {
+            name: NAME.to_string(),
+            record_name: "twitter".to_string(),
+        };
+        let verified: bool = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert!(verified);
+
+        let verified_at = app.block_info().time;
+        update_block_time(&mut app, SECONDS_PER_YEAR);
+
+        let verified: bool = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert!(!verified);
+
+        let msg = Sg721NameQueryMsg::TextRecords {
+            name: NAME.to_string(),
+            start_after: None,
+            limit: None,
+            verified_only: None,
+        };
+        let records: Vec<TextRecord> = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(records[0].verified, None);
+        assert_eq!(records[0].verified_at, Some(verified_at));
+
+        // the owner asks oracles to verify the record again
+        let msg = SgNameExecuteMsg::RequestVerification {
+            name: NAME.to_string(),
+            record_name: "twitter".to_string(),
+        };
+        let res = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                Addr::unchecked(COLLECTION),
+                &msg,
+                &[],
             )
             .unwrap();
-        assert_eq!(res.extension.records[0].name, name.to_string());
-        assert_eq!(res.extension.records[0].verified, Some(false));
+        assert!(res.has_event(
+            &Event::new("wasm-request-verification")
+                .add_attribute("record", "twitter")
+                .add_attribute("value", "shan3v")
+        ));
     }
 
     #[test]
-    fn verified_text_record() {
+    fn scoped_verifiers() {
         let mut app = instantiate_contracts(None, None, None);
 
         let res = mint_and_list(&mut app, NAME, USER, None);
         assert!(res.is_ok());
 
-        let name = "twitter";
-        let value = "shan3v";
+        for (name, value) in [("twitter", "shan3v"), ("social-discord", "shan3v#0001")] {
+            let msg = SgNameExecuteMsg::AddTextRecord {
+                name: NAME.to_string(),
+                record: TextRecord::new(name, value),
+            };
+            let res = app.execute_contract(
+                Addr::unchecked(USER),
+                Addr::unchecked(COLLECTION),
+                &msg,
+                &[],
+            );
+            assert!(res.is_ok());
+        }
 
-        let msg = SgNameExecuteMsg::AddTextRecord {
-            name: NAME.to_string(),
-            record: TextRecord {
-                name: name.to_string(),
-                value: value.to_string(),
-                verified: Some(true),
-            },
+        // only a verifier of all records can add verifiers
+        let msg = SgNameExecuteMsg::AddVerifier {
+            verifier: USER2.to_string(),
+            scope: "social-*".to_string(),
         };
-
         let res = app.execute_contract(
-            Addr::unchecked(USER),
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_err());
+        let res = app.execute_contract(
+            Addr::unchecked(VERIFIER),
             Addr::unchecked(COLLECTION),
             &msg,
             &[],
         );
         assert!(res.is_ok());
 
-        // query text record to see if verified is not set
-        let res: NftInfoResponse<Metadata> = app
-            .wrap()
-            .query_wasm_smart(
-                COLLECTION,
-                &(Sg721NameQueryMsg::NftInfo {
-                    token_id: NAME.to_string(),
-                }),
-            )
-            .unwrap();
-        assert_eq!(res.extension.records[0].name, name.to_string());
-        assert_eq!(res.extension.records[0].verified, None);
-
-        // attempt update text record w verified value
-        let msg = SgNameExecuteMsg::UpdateTextRecord {
+        let verify = |record_name: &str| SgNameExecuteMsg::VerifyTextRecord {
             name: NAME.to_string(),
-            record: TextRecord {
-                name: name.to_string(),
-                value: "some new value".to_string(),
-                verified: Some(true),
-            },
+            record_name: record_name.to_string(),
+            result: true,
         };
+
+        // out of scope
         let res = app.execute_contract(
-            Addr::unchecked(USER),
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &verify("twitter"),
+            &[],
+        );
+        assert!(res.is_err());
+
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &verify("social-discord"),
+            &[],
+        );
+        assert!(res.is_ok());
+
+        let msg = SgNameExecuteMsg::RemoveVerifier {
+            verifier: USER2.to_string(),
+            scope: "social-*".to_string(),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(VERIFIER),
             Addr::unchecked(COLLECTION),
             &msg,
             &[],
         );
         assert!(res.is_ok());
 
-        // query text record to see if verified is not set
-        let res: NftInfoResponse<Metadata> = app
-            .wrap()
-            .query_wasm_smart(
-                COLLECTION,
-                &(Sg721NameQueryMsg::NftInfo {
-                    token_id: NAME.to_string(),
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &verify("social-discord"),
+            &[],
+        );
+        assert!(res.is_err());
+
+        let msg = Sg721NameQueryMsg::Verifiers {};
+        let verifiers: Vec<VerifierResponse> =
+            app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(verifiers.len(), 1);
+    }
+
+    #[test]
+    fn name_by_record() {
+        let mut app = instantiate_contracts(None, None, None);
+        let second = "second";
+
+        let res = mint_and_list(&mut app, NAME, USER, None);
+        assert!(res.is_ok());
+        let res = mint_and_list(&mut app, second, USER2, None);
+        assert!(res.is_ok());
+
+        for (name, owner) in [(NAME, USER), (second, USER2)] {
+            let msg = SgNameExecuteMsg::AddTextRecord {
+                name: name.to_string(),
+                record: TextRecord::new("twitter", "shan3v"),
+            };
+            let res = app.execute_contract(
+                Addr::unchecked(owner),
+                Addr::unchecked(COLLECTION),
+                &msg,
+                &[],
+            );
+            assert!(res.is_ok());
+        }
+
+        let query = |verified_only: bool| Sg721NameQueryMsg::NameByRecord {
+            record_name: "twitter".to_string(),
+            value: "shan3v".to_string(),
+            verified_only,
+        };
+        let res: String = app
+            .wrap()
+            .query_wasm_smart(COLLECTION, &query(false))
+            .unwrap();
+        assert_eq!(res, NAME);
+        let res: StdResult<String> = app.wrap().query_wasm_smart(COLLECTION, &query(true));
+        assert!(res.is_err());
+
+        let verify = |name: &str| SgNameExecuteMsg::VerifyTextRecord {
+            name: name.to_string(),
+            record_name: "twitter".to_string(),
+            result: true,
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(VERIFIER),
+            Addr::unchecked(COLLECTION),
+            &verify(second),
+            &[],
+        );
+        assert!(res.is_ok());
+
+        // the verified claim wins
+        let res: String = app
+            .wrap()
+            .query_wasm_smart(COLLECTION, &query(false))
+            .unwrap();
+        assert_eq!(res, second);
+        let res: String = app
+            .wrap()
+            .query_wasm_smart(COLLECTION, &query(true))
+            .unwrap();
+        assert_eq!(res, second);
+
+        // a verified value can only belong to one name
+        let res = app.execute_contract(
+            Addr::unchecked(VERIFIER),
+            Addr::unchecked(COLLECTION),
+            &verify(NAME),
+            &[],
+        );
+        assert!(res.is_err());
+
+        let msg = SgNameExecuteMsg::RemoveTextRecord {
+            name: second.to_string(),
+            record_name: "twitter".to_string(),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        let res: String = app
+            .wrap()
+            .query_wasm_smart(COLLECTION, &query(false))
+            .unwrap();
+        assert_eq!(res, NAME);
+        let res = app.execute_contract(
+            Addr::unchecked(VERIFIER),
+            Addr::unchecked(COLLECTION),
+            &verify(NAME),
+            &[],
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn image_nft_ownership() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let res = mint_and_list(&mut app, NAME, USER, None);
+        assert!(res.is_ok());
+
+        let nft_id = app.store_code(contract_nft());
+        let init_msg = sg721::InstantiateMsg {
+            name: "NFT".to_string(),
+            symbol: "NFT".to_string(),
+            minter: MINTER.to_string(),
+            collection_info: sg721::CollectionInfo {
+                creator: ADMIN.to_string(),
+                description: "Profile pictures".to_string(),
+                image: "ipfs://example.com".to_string(),
+                external_link: None,
+                explicit_content: None,
+                start_trading_time: None,
+                royalty_info: None,
+            },
+        };
+        let nft_addr = app
+            .instantiate_contract(nft_id, Addr::unchecked(MINTER), &init_msg, &[], "NFT", None)
+            .unwrap();
+
+        for (token_id, owner) in [("1", USER), ("2", USER2)] {
+            let msg = sg721::ExecuteMsg::<Option<Empty>, Empty>::Mint(MintMsg {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: None,
+            });
+            let res = app.execute_contract(Addr::unchecked(MINTER), nft_addr.clone(), &msg, &[]);
+            assert!(res.is_ok());
+        }
+
+        let update_image = |token_id: &str| SgNameExecuteMsg::UpdateImageNft {
+            name: NAME.to_string(),
+            nft: Some(NFT {
+                collection: nft_addr.clone(),
+                token_id: token_id.to_string(),
+            }),
+        };
+
+        // can't use someone else's NFT
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &update_image("2"),
+            &[],
+        );
+        assert!(res.is_err());
+
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &update_image("1"),
+            &[],
+        );
+        assert!(res.is_ok());
+
+        let msg = Sg721NameQueryMsg::ImageNFTStatus {
+            name: NAME.to_string(),
+        };
+        let res: ImageNftStatusResponse = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert!(res.owned);
+
+        // the image goes stale once the NFT is sold
+        let transfer_msg = sg721::ExecuteMsg::<Option<Empty>, Empty>::TransferNft {
+            recipient: USER2.to_string(),
+            token_id: "1".to_string(),
+        };
+        let res = app.execute_contract(Addr::unchecked(USER), nft_addr.clone(), &transfer_msg, &[]);
+        assert!(res.is_ok());
+
+        let res: ImageNftStatusResponse = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(res.nft.unwrap().token_id, "1");
+        assert!(!res.owned);
+    }
+
+    #[test]
+    fn verify_false() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let res = mint_and_list(&mut app, NAME, USER, None);
+        assert!(res.is_ok());
+
+        let name = "twitter";
+        let value = "shan3v";
+
+        let msg = SgNameExecuteMsg::AddTextRecord {
+            name: NAME.to_string(),
+            record: TextRecord::new(name, value),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        // verify something as false
+        let msg = SgNameExecuteMsg::VerifyTextRecord {
+            name: NAME.to_string(),
+            record_name: name.to_string(),
+            result: false,
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(VERIFIER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        // query text record to see if verified is set
+        let res: NftInfoResponse<Metadata> = app
+            .wrap()
+            .query_wasm_smart(
+                COLLECTION,
+                &(Sg721NameQueryMsg::NftInfo {
+                    token_id: NAME.to_string(),
+                }),
+            )
+            .unwrap();
+        assert_eq!(res.extension.records[0].name, name.to_string());
+        assert_eq!(res.extension.records[0].verified, Some(false));
+    }
+
+    #[test]
+    fn verified_text_record() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let res = mint_and_list(&mut app, NAME, USER, None);
+        assert!(res.is_ok());
+
+        let name = "twitter";
+        let value = "shan3v";
+
+        let msg = SgNameExecuteMsg::AddTextRecord {
+            name: NAME.to_string(),
+            record: TextRecord {
+                name: name.to_string(),
+                value: value.to_string(),
+                verified: Some(true),
+                verified_at: None,
+            },
+        };
+
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        // query text record to see if verified is not set
+        let res: NftInfoResponse<Metadata> = app
+            .wrap()
+            .query_wasm_smart(
+                COLLECTION,
+                &(Sg721NameQueryMsg::NftInfo {
+                    token_id: NAME.to_string(),
+                }),
+            )
+            .unwrap();
+        assert_eq!(res.extension.records[0].name, name.to_string());
+        assert_eq!(res.extension.records[0].verified, None);
+
+        // attempt update text record w verified value
+        let msg = SgNameExecuteMsg::UpdateTextRecord {
+            name: NAME.to_string(),
+            record: TextRecord {
+                name: name.to_string(),
+                value: "shan3v_new".to_string(),
+                verified: Some(true),
+                verified_at: None,
+            },
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        // query text record to see if verified is not set
+        let res: NftInfoResponse<Metadata> = app
+            .wrap()
+            .query_wasm_smart(
+                COLLECTION,
+                &(Sg721NameQueryMsg::NftInfo {
+                    token_id: NAME.to_string(),
                 }),
             )
             .unwrap();
@@ -1993,7 +2643,497 @@ mod collection {
 
         bid(&mut app, NAME, BIDDER, BID_AMOUNT);
 
-        // user2 must approve the marketplace to transfer their name
+        // user2 must approve the marketplace to transfer their name
+        let msg = Sg721NameExecuteMsg::Approve {
+            spender: MKT.to_string(),
+            token_id: NAME.to_string(),
+            expires: None,
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        // accept bid
+        let msg = MarketplaceExecuteMsg::AcceptBid {
+            token_id: NAME.to_string(),
+            bidder: BIDDER.to_string(),
+        };
+        let res = app.execute_contract(Addr::unchecked(USER2), Addr::unchecked(MKT), &msg, &[]);
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn transfer_nft_with_reverse_map() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let user = "stars1hsk6jryyqjfhp5dhc55tc9jtckygx0eprx6sym";
+        let user2 = "stars1wh3wjjgprxeww4cgqyaw8k75uslzh3sd3s2yfk";
+        let res = mint_and_list(&mut app, NAME, user, None);
+        assert!(res.is_ok());
+
+        let msg = SgNameExecuteMsg::AssociateAddress {
+            name: NAME.to_string(),
+            address: Some(user.to_string()),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(user),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        let msg = SgNameQueryMsg::Name {
+            address: user.to_string(),
+        };
+        let res: String = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(res, NAME.to_string());
+
+        transfer(&mut app, user, user2);
+
+        let msg = SgNameQueryMsg::Name {
+            address: user.to_string(),
+        };
+        let err: StdResult<String> = app.wrap().query_wasm_smart(COLLECTION, &msg);
+        assert!(err.is_err());
+
+        let msg = SgNameQueryMsg::Name {
+            address: user2.to_string(),
+        };
+        let err: StdResult<String> = app.wrap().query_wasm_smart(COLLECTION, &msg);
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn managers_cleared_on_transfer() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let res = mint_and_list(&mut app, NAME, USER, None);
+        assert!(res.is_ok());
+
+        let msg = Sg721NameExecuteMsg::AddManager {
+            name: NAME.to_string(),
+            manager: USER3.to_string(),
+            expires: None,
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        // a manager can't move the name
+        let msg = Sg721NameExecuteMsg::TransferNft {
+            recipient: USER3.to_string(),
+            token_id: NAME.to_string(),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER3),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_err());
+
+        transfer(&mut app, USER, USER2);
+
+        let msg = Sg721NameQueryMsg::Managers {
+            name: NAME.to_string(),
+        };
+        let res: Vec<ManagerResponse> = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert!(res.is_empty());
+
+        let msg = Sg721NameExecuteMsg::AddTextRecord {
+            name: NAME.to_string(),
+            record: TextRecord::new("discord", "bobo#0001"),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER3),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn transfer_with_metadata() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let res = mint_and_list(&mut app, NAME, USER, None);
+        assert!(res.is_ok());
+
+        let msgs = [
+            SgNameExecuteMsg::AddTextRecord {
+                name: NAME.to_string(),
+                record: TextRecord::new("twitter", "shan3v"),
+            },
+            SgNameExecuteMsg::AssociateAddress {
+                name: NAME.to_string(),
+                address: Some(USER.to_string()),
+            },
+        ];
+        for msg in msgs {
+            let res = app.execute_contract(
+                Addr::unchecked(USER),
+                Addr::unchecked(COLLECTION),
+                &msg,
+                &[],
+            );
+            assert!(res.is_ok());
+        }
+        let msg = SgNameExecuteMsg::VerifyTextRecord {
+            name: NAME.to_string(),
+            record_name: "twitter".to_string(),
+            result: true,
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(VERIFIER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        let msg = SgNameExecuteMsg::TransferWithMetadata {
+            recipient: USER2.to_string(),
+            token_id: NAME.to_string(),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+        assert_eq!(owner_of(&app, NAME.to_string()), USER2.to_string());
+
+        // the ask follows the name
+        let msg = MarketplaceQueryMsg::Ask {
+            token_id: NAME.to_string(),
+        };
+        let res: Option<Ask> = app.wrap().query_wasm_smart(MKT, &msg).unwrap();
+        assert_eq!(res.unwrap().seller.to_string(), USER2.to_string());
+
+        // records are kept, verifications and the association are not
+        let msg = Sg721NameQueryMsg::TextRecords {
+            name: NAME.to_string(),
+            start_after: None,
+            limit: None,
+            verified_only: None,
+        };
+        let records: Vec<TextRecord> = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(records, vec![TextRecord::new("twitter", "shan3v")]);
+
+        let msg = SgNameQueryMsg::AssociatedAddress {
+            name: NAME.to_string(),
+        };
+        let res: StdResult<String> = app.wrap().query_wasm_smart(COLLECTION, &msg);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn fuses() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let res = mint_and_list(&mut app, NAME, USER, None);
+        assert!(res.is_ok());
+
+        let msg = SgNameExecuteMsg::AssociateAddress {
+            name: NAME.to_string(),
+            address: Some(USER.to_string()),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        let msg = SgNameExecuteMsg::BurnFuses {
+            name: NAME.to_string(),
+            fuses: CANNOT_SET_ADDRESS | CANNOT_TRANSFER,
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_err());
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        let msg = SgNameQueryMsg::Fuses {
+            name: NAME.to_string(),
+        };
+        let fuses: u32 = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(fuses, CANNOT_SET_ADDRESS | CANNOT_TRANSFER);
+
+        let msg = SgNameExecuteMsg::AssociateAddress {
+            name: NAME.to_string(),
+            address: None,
+        };
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                Addr::unchecked(COLLECTION),
+                &msg,
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            sg721_name::ContractError::FuseBurned {}.to_string()
+        );
+
+        // records are not fused
+        let msg = SgNameExecuteMsg::AddTextRecord {
+            name: NAME.to_string(),
+            record: TextRecord::new("twitter", "shan3v"),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        let msg = Sg721NameExecuteMsg::TransferNft {
+            recipient: USER2.to_string(),
+            token_id: NAME.to_string(),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_err());
+
+        // a sale still goes through and keeps the fused address
+        bid(&mut app, NAME, BIDDER, BID_AMOUNT);
+        let msg = MarketplaceExecuteMsg::AcceptBid {
+            token_id: NAME.to_string(),
+            bidder: BIDDER.to_string(),
+        };
+        let res = app.execute_contract(Addr::unchecked(USER), Addr::unchecked(MKT), &msg, &[]);
+        assert!(res.is_ok());
+        assert_eq!(owner_of(&app, NAME.to_string()), BIDDER.to_string());
+
+        let msg = SgNameQueryMsg::AssociatedAddress {
+            name: NAME.to_string(),
+        };
+        let address: String = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(address, USER.to_string());
+
+        let msg = Sg721NameQueryMsg::TextRecords {
+            name: NAME.to_string(),
+            start_after: None,
+            limit: None,
+            verified_only: None,
+        };
+        let records: Vec<TextRecord> = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert!(records.is_empty());
+
+        let msg = SgNameQueryMsg::Fuses {
+            name: NAME.to_string(),
+        };
+        let fuses: u32 = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(fuses, CANNOT_SET_ADDRESS | CANNOT_TRANSFER);
+    }
+
+    #[test]
+    fn primary_name() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        for name in [NAME, NAME2] {
+            let res = mint_and_list(&mut app, name, USER, None);
+            assert!(res.is_ok());
+        }
+
+        let primary_name = |app: &StargazeApp, address: &str| -> Option<String> {
+            let msg = SgNameQueryMsg::PrimaryName {
+                address: address.to_string(),
+            };
+            app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap()
+        };
+        assert_eq!(primary_name(&app, USER), None);
+
+        // falls back to the associated name
+        let msg = SgNameExecuteMsg::AssociateAddress {
+            name: NAME2.to_string(),
+            address: Some(USER.to_string()),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+        assert_eq!(primary_name(&app, USER), Some(NAME2.to_string()));
+
+        let msg = SgNameExecuteMsg::SetPrimaryName {
+            name: Some(NAME.to_string()),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_err());
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+        assert_eq!(primary_name(&app, USER), Some(NAME.to_string()));
+
+        // a transfer clears the primary name
+        transfer(&mut app, USER, USER2);
+        assert_eq!(primary_name(&app, USER), Some(NAME2.to_string()));
+        assert_eq!(primary_name(&app, USER2), None);
+
+        let msg = SgNameExecuteMsg::SetPrimaryName {
+            name: Some(NAME.to_string()),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+        assert_eq!(primary_name(&app, USER2), Some(NAME.to_string()));
+
+        // so does a sale
+        bid(&mut app, NAME, BIDDER, BID_AMOUNT);
+        let msg = Sg721NameExecuteMsg::Approve {
+            spender: MKT.to_string(),
+            token_id: NAME.to_string(),
+            expires: None,
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+        let msg = MarketplaceExecuteMsg::AcceptBid {
+            token_id: NAME.to_string(),
+            bidder: BIDDER.to_string(),
+        };
+        let res = app.execute_contract(Addr::unchecked(USER2), Addr::unchecked(MKT), &msg, &[]);
+        assert!(res.is_ok());
+        assert_eq!(primary_name(&app, USER2), None);
+    }
+
+    #[test]
+    fn subnames_survive_transfer_but_not_sale() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let res = mint_and_list(&mut app, NAME, USER, None);
+        assert!(res.is_ok());
+
+        let msg = SgNameExecuteMsg::CreateSubname {
+            parent: NAME.to_string(),
+            label: "pay".to_string(),
+            owner: USER3.to_string(),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+
+        // subname holders can move their subname without touching the marketplace
+        let msg = Sg721NameExecuteMsg::TransferNft {
+            recipient: USER4.to_string(),
+            token_id: format!("pay.{}", NAME),
+        };
+        let res = app.execute_contract(
+            Addr::unchecked(USER3),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        );
+        assert!(res.is_ok());
+        assert_eq!(owner_of(&app, format!("pay.{}", NAME)), USER4.to_string());
+
+        // a subname whose owner burned fuses is out of the parent's control
+        let treasury = format!("treasury.{}", NAME);
+        let msg = SgNameExecuteMsg::CreateSubname {
+            parent: NAME.to_string(),
+            label: "treasury".to_string(),
+            owner: USER3.to_string(),
+        };
+        app.execute_contract(
+            Addr::unchecked(USER),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        )
+        .unwrap();
+        let msg = SgNameExecuteMsg::BurnFuses {
+            name: treasury.clone(),
+            fuses: CANNOT_SET_ADDRESS,
+        };
+        app.execute_contract(
+            Addr::unchecked(USER3),
+            Addr::unchecked(COLLECTION),
+            &msg,
+            &[],
+        )
+        .unwrap();
+        let msg = SgNameExecuteMsg::RevokeSubname {
+            name: treasury.clone(),
+        };
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                Addr::unchecked(COLLECTION),
+                &msg,
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            sg721_name::ContractError::FuseBurned {}.to_string()
+        );
+
+        // moving the parent between wallets keeps its subnames
+        transfer(&mut app, USER, USER2);
+
+        let msg = SgNameQueryMsg::Subnames {
+            parent: NAME.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let res: Vec<String> = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(res, vec![format!("pay.{}", NAME), treasury.clone()]);
+
+        bid(&mut app, NAME, BIDDER, BID_AMOUNT);
+
         let msg = Sg721NameExecuteMsg::Approve {
             spender: MKT.to_string(),
             token_id: NAME.to_string(),
@@ -2007,55 +3147,28 @@ mod collection {
         );
         assert!(res.is_ok());
 
-        // accept bid
         let msg = MarketplaceExecuteMsg::AcceptBid {
             token_id: NAME.to_string(),
             bidder: BIDDER.to_string(),
         };
         let res = app.execute_contract(Addr::unchecked(USER2), Addr::unchecked(MKT), &msg, &[]);
         assert!(res.is_ok());
-    }
-
-    #[test]
-    fn transfer_nft_with_reverse_map() {
-        let mut app = instantiate_contracts(None, None, None);
-
-        let user = "stars1hsk6jryyqjfhp5dhc55tc9jtckygx0eprx6sym";
-        let user2 = "stars1wh3wjjgprxeww4cgqyaw8k75uslzh3sd3s2yfk";
-        let res = mint_and_list(&mut app, NAME, user, None);
-        assert!(res.is_ok());
-
-        let msg = SgNameExecuteMsg::AssociateAddress {
-            name: NAME.to_string(),
-            address: Some(user.to_string()),
-        };
-        let res = app.execute_contract(
-            Addr::unchecked(user),
-            Addr::unchecked(COLLECTION),
-            &msg,
-            &[],
-        );
-        assert!(res.is_ok());
 
-        let msg = SgNameQueryMsg::Name {
-            address: user.to_string(),
-        };
-        let res: String = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
-        assert_eq!(res, NAME.to_string());
-
-        transfer(&mut app, user, user2);
-
-        let msg = SgNameQueryMsg::Name {
-            address: user.to_string(),
+        // the sale invalidates the subnames, except the fused one
+        let msg = SgNameQueryMsg::Subnames {
+            parent: NAME.to_string(),
+            start_after: None,
+            limit: None,
         };
-        let err: StdResult<String> = app.wrap().query_wasm_smart(COLLECTION, &msg);
-        assert!(err.is_err());
+        let res: Vec<String> = app.wrap().query_wasm_smart(COLLECTION, &msg).unwrap();
+        assert_eq!(res, vec![treasury.clone()]);
+        assert_eq!(owner_of(&app, treasury), USER3);
 
-        let msg = SgNameQueryMsg::Name {
-            address: user2.to_string(),
-        };
-        let err: StdResult<String> = app.wrap().query_wasm_smart(COLLECTION, &msg);
-        assert!(err.is_err());
+        let res: NumTokensResponse = app
+            .wrap()
+            .query_wasm_smart(COLLECTION, &(sg721_base::msg::QueryMsg::NumTokens {}))
+            .unwrap();
+        assert_eq!(res.count, 2);
     }
 
     // test that burn nft currently does nothing. this is a placeholder for future functionality
@@ -2644,6 +3757,431 @@ mod public_start_time {
     }
 }
 
+mod batch_mint {
+    use crate::ContractError;
+
+    use super::*;
+
+    fn batch_mint(
+        app: &mut StargazeApp,
+        user: &str,
+        names: &[&str],
+        amount: u128,
+    ) -> AnyResult<AppResponse> {
+        let approve_all_msg = Sg721NameExecuteMsg::ApproveAll {
+            operator: MKT.to_string(),
+            expires: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(user),
+            Addr::unchecked(COLLECTION),
+            &approve_all_msg,
+            &[],
+        )
+        .unwrap();
+
+        let funds = coins(amount, NATIVE_DENOM);
+        app.sudo(CwSudoMsg::Bank(BankSudo::Mint {
+            to_address: user.to_string(),
+            amount: funds.clone(),
+        }))
+        .unwrap();
+
+        let msg = ExecuteMsg::BatchMintAndList {
+            names: names.iter().map(|name| name.to_string()).collect(),
+        };
+        app.execute_contract(Addr::unchecked(user), Addr::unchecked(MINTER), &msg, &funds)
+    }
+
+    #[test]
+    fn batch_mint_and_list() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        // prices of all names are summed up
+        let err = batch_mint(&mut app, USER2, &[NAME, NAME2], BASE_PRICE).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::IncorrectPayment {
+                got: BASE_PRICE,
+                expected: BASE_PRICE * 11,
+            }
+            .to_string()
+        );
+        let err = batch_mint(&mut app, USER2, &[NAME, "a"], BASE_PRICE * 11).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::NameTooShort {}.to_string()
+        );
+
+        batch_mint(&mut app, USER2, &[NAME, NAME2], BASE_PRICE * 11).unwrap();
+        assert_eq!(owner_of(&app, NAME.to_string()), USER2);
+        assert_eq!(owner_of(&app, NAME2.to_string()), USER2);
+
+        // a taken name fails the whole batch
+        let res = batch_mint(&mut app, USER3, &["brand", NAME2], BASE_PRICE * 2);
+        assert!(res.is_err());
+        let res: NumTokensResponse = app
+            .wrap()
+            .query_wasm_smart(COLLECTION, &sg721_base::msg::QueryMsg::NumTokens {})
+            .unwrap();
+        assert_eq!(res.count, 2);
+    }
+
+    #[test]
+    fn batch_mint_whitelist_limit() {
+        let mut app = instantiate_contracts(None, Some(ADMIN.to_string()), None);
+
+        // every name counts towards the per address limit of the whitelist
+        let names = ["brand", "brand-two", "brand-three"];
+        let err = batch_mint(&mut app, USER, &names, BASE_PRICE * 3).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            whitelist_updatable_flatrate::ContractError::OverPerAddressLimit {}.to_string()
+        );
+
+        batch_mint(&mut app, USER, &names[..2], BASE_PRICE * 2).unwrap();
+        assert_eq!(owner_of(&app, "brand-two".to_string()), USER);
+    }
+}
+
+mod reserved_names {
+    use sg_name_minter::{Reservation, ReservedName};
+
+    use crate::msg::{QueryMsg, SudoMsg};
+    use crate::ContractError;
+
+    use super::*;
+
+    #[test]
+    fn reserved_names() {
+        let mut app = instantiate_contracts(None, Some(ADMIN.to_string()), None);
+
+        let msg = ExecuteMsg::AddReservedNames {
+            names: vec![
+                ReservedName {
+                    name: "stargaze".to_string(),
+                    owner: None,
+                    price: None,
+                },
+                ReservedName {
+                    name: "brand".to_string(),
+                    owner: Some(USER2.to_string()),
+                    price: Some(Uint128::from(5u128)),
+                },
+            ],
+        };
+        let res = app.execute_contract(Addr::unchecked(USER), Addr::unchecked(MINTER), &msg, &[]);
+        assert!(res.is_err());
+        app.execute_contract(Addr::unchecked(ADMIN), Addr::unchecked(MINTER), &msg, &[])
+            .unwrap();
+
+        let res: Vec<(String, Reservation)> = app
+            .wrap()
+            .query_wasm_smart(
+                MINTER,
+                &QueryMsg::ReservedNames {
+                    start_after: Some("brand".to_string()),
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            res,
+            vec![(
+                "stargaze".to_string(),
+                Reservation {
+                    owner: None,
+                    price: None,
+                }
+            )]
+        );
+
+        // blocked for everyone
+        for user in [USER2, USER3] {
+            let err = mint_and_list(&mut app, "stargaze", user, None).unwrap_err();
+            assert_eq!(
+                err.root_cause().to_string(),
+                ContractError::NameReserved {}.to_string()
+            );
+        }
+        let err = mint_and_list(&mut app, "brand", USER3, None).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::NameReserved {}.to_string()
+        );
+
+        // the reserved owner mints at the set price
+        let approve_all_msg = Sg721NameExecuteMsg::ApproveAll {
+            operator: MKT.to_string(),
+            expires: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(COLLECTION),
+            &approve_all_msg,
+            &[],
+        )
+        .unwrap();
+        let price = coins(5, NATIVE_DENOM);
+        app.sudo(CwSudoMsg::Bank(BankSudo::Mint {
+            to_address: USER2.to_string(),
+            amount: price.clone(),
+        }))
+        .unwrap();
+        let msg = ExecuteMsg::MintAndList {
+            name: "brand".to_string(),
+            years: None,
+            owner: None,
+            metadata: None,
+            associate: false,
+        };
+        app.execute_contract(
+            Addr::unchecked(USER2),
+            Addr::unchecked(MINTER),
+            &msg,
+            &price,
+        )
+        .unwrap();
+        assert_eq!(owner_of(&app, "brand".to_string()), USER2);
+
+        let msg = SudoMsg::RemoveReservedNames {
+            names: vec!["stargaze".to_string()],
+        };
+        app.wasm_sudo(Addr::unchecked(MINTER), &msg).unwrap();
+        mint_and_list(&mut app, "stargaze", USER3, None).unwrap();
+        assert_eq!(owner_of(&app, "stargaze".to_string()), USER3);
+    }
+}
+
+mod commit_reveal {
+    use crate::helpers::make_commitment;
+    use crate::msg::{QueryMsg, SudoMsg};
+    use crate::state::Commitment;
+    use crate::ContractError;
+
+    use super::*;
+
+    const SALT: &str = "salt";
+
+    fn commit(app: &mut StargazeApp, user: &str, name: &str) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::CommitMint {
+            commitment: make_commitment(name, user, SALT),
+        };
+        app.execute_contract(Addr::unchecked(user), Addr::unchecked(MINTER), &msg, &[])
+    }
+
+    fn reveal(app: &mut StargazeApp, user: &str, name: &str) -> AnyResult<AppResponse> {
+        reveal_for(app, user, name, None)
+    }
+
+    fn reveal_for(
+        app: &mut StargazeApp,
+        user: &str,
+        name: &str,
+        owner: Option<&str>,
+    ) -> AnyResult<AppResponse> {
+        let approve_all_msg = Sg721NameExecuteMsg::ApproveAll {
+            operator: MKT.to_string(),
+            expires: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(user),
+            Addr::unchecked(COLLECTION),
+            &approve_all_msg,
+            &[],
+        )
+        .unwrap();
+
+        let name_fee = coins(BASE_PRICE, NATIVE_DENOM);
+        app.sudo(CwSudoMsg::Bank(BankSudo::Mint {
+            to_address: user.to_string(),
+            amount: name_fee.clone(),
+        }))
+        .unwrap();
+
+        let msg = ExecuteMsg::RevealMint {
+            name: name.to_string(),
+            salt: SALT.to_string(),
+            years: None,
+            owner: owner.map(str::to_string),
+            metadata: None,
+            associate: false,
+        };
+        app.execute_contract(
+            Addr::unchecked(user),
+            Addr::unchecked(MINTER),
+            &msg,
+            &name_fee,
+        )
+    }
+
+    #[test]
+    fn commit_and_reveal() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        commit(&mut app, USER2, NAME2).unwrap();
+        let err = commit(&mut app, USER2, NAME2).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::CommitmentExists {}.to_string()
+        );
+        let res: Option<Commitment> = app
+            .wrap()
+            .query_wasm_smart(
+                MINTER,
+                &QueryMsg::Commitment {
+                    commitment: make_commitment(NAME2, USER2, SALT),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.unwrap().owner, USER2);
+
+        // too early
+        let err = reveal(&mut app, USER2, NAME2).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::CommitmentTooNew(60).to_string()
+        );
+
+        update_block_time(&mut app, 60);
+
+        // a copied reveal doesn't match the commitment of another sender
+        let err = reveal(&mut app, USER3, NAME2).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::CommitmentNotFound {}.to_string()
+        );
+        let err = reveal_for(&mut app, USER3, NAME2, Some(USER2)).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::CommitmentNotOwned {}.to_string()
+        );
+
+        reveal(&mut app, USER2, NAME2).unwrap();
+        assert_eq!(owner_of(&app, NAME2.to_string()), USER2);
+
+        // the commitment is used up
+        let res: Option<Commitment> = app
+            .wrap()
+            .query_wasm_smart(
+                MINTER,
+                &QueryMsg::Commitment {
+                    commitment: make_commitment(NAME2, USER2, SALT),
+                },
+            )
+            .unwrap();
+        assert!(res.is_none());
+    }
+
+    #[test]
+    fn reveal_for_owner() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        // the owner is part of the commitment
+        let msg = ExecuteMsg::CommitMint {
+            commitment: make_commitment(NAME2, USER3, SALT),
+        };
+        app.execute_contract(Addr::unchecked(USER2), Addr::unchecked(MINTER), &msg, &[])
+            .unwrap();
+        update_block_time(&mut app, 60);
+
+        let err = reveal(&mut app, USER2, NAME2).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::CommitmentNotFound {}.to_string()
+        );
+
+        // the owner approves the marketplace to be listed
+        let approve_all_msg = Sg721NameExecuteMsg::ApproveAll {
+            operator: MKT.to_string(),
+            expires: None,
+        };
+        app.execute_contract(
+            Addr::unchecked(USER3),
+            Addr::unchecked(COLLECTION),
+            &approve_all_msg,
+            &[],
+        )
+        .unwrap();
+        reveal_for(&mut app, USER2, NAME2, Some(USER3)).unwrap();
+        assert_eq!(owner_of(&app, NAME2.to_string()), USER3);
+    }
+
+    #[test]
+    fn commitment_expires() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        commit(&mut app, USER2, NAME2).unwrap();
+        update_block_time(&mut app, 24 * 60 * 60 + 1);
+        let err = reveal(&mut app, USER2, NAME2).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::CommitmentExpired {}.to_string()
+        );
+
+        // new commitments prune expired ones
+        commit(&mut app, USER3, NAME).unwrap();
+        let res: Option<Commitment> = app
+            .wrap()
+            .query_wasm_smart(
+                MINTER,
+                &QueryMsg::Commitment {
+                    commitment: make_commitment(NAME2, USER2, SALT),
+                },
+            )
+            .unwrap();
+        assert!(res.is_none());
+
+        // and an expired commitment can be made again
+        commit(&mut app, USER2, NAME2).unwrap();
+    }
+
+    #[test]
+    fn commit_reveal_required() {
+        let mut app = instantiate_contracts(None, None, None);
+
+        let msg = SudoMsg::UpdateCommitRevealParams {
+            min_age: 10,
+            max_age: 10,
+            required: true,
+        };
+        let err = app.wasm_sudo(Addr::unchecked(MINTER), &msg).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::InvalidCommitRevealParams {}.to_string()
+        );
+
+        let msg = SudoMsg::UpdateCommitRevealParams {
+            min_age: 10,
+            max_age: 100,
+            required: true,
+        };
+        app.wasm_sudo(Addr::unchecked(MINTER), &msg).unwrap();
+
+        let err = mint_and_list(&mut app, NAME2, USER2, None).unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::CommitRevealRequired {}.to_string()
+        );
+        // batches can't be committed to, so they are unavailable too
+        let msg = ExecuteMsg::BatchMintAndList {
+            names: vec![NAME2.to_string()],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER2), Addr::unchecked(MINTER), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            ContractError::CommitRevealRequired {}.to_string()
+        );
+
+        commit(&mut app, USER2, NAME2).unwrap();
+        update_block_time(&mut app, 10);
+        reveal(&mut app, USER2, NAME2).unwrap();
+        assert_eq!(owner_of(&app, NAME2.to_string()), USER2);
+    }
+}
+
 mod associate_address {
     use super::*;
 
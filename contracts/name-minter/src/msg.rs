@@ -1,6 +1,9 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
-use sg_name_minter::{Config, SudoParams};
+use cosmwasm_std::{Addr, Binary, Uint128};
+use sg_name::Metadata;
+use sg_name_minter::{Config, MultiYearDiscount, Reservation, ReservedName, SudoParams};
+
+use crate::state::{CommitRevealParams, Commitment};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -20,7 +23,34 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Mint a name and list on Stargaze Name Marketplace
-    MintAndList { name: String },
+    /// for `years` (1 if not set) before the first renewal.
+    /// The sender pays for a name minted to `owner`, which has to approve the
+    /// marketplace beforehand. `associate` sets `owner` as the associated address,
+    /// only when it is the sender so a gift can't replace the owner's mapping.
+    MintAndList {
+        name: String,
+        years: Option<u32>,
+        owner: Option<String>,
+        metadata: Option<Metadata>,
+        #[serde(default)]
+        associate: bool,
+    },
+    /// Mint and list several names for the sender, paying the sum of their prices
+    BatchMintAndList { names: Vec<String> },
+    /// Commit to minting a name without revealing it.
+    /// `commitment` is `sha256("{name}:{owner}:{salt}")`, see `helpers::make_commitment`.
+    CommitMint { commitment: Binary },
+    /// Mint and list a name committed to by the sender with `CommitMint`.
+    /// Takes the same options as `MintAndList`, `owner` is part of the commitment.
+    RevealMint {
+        name: String,
+        salt: String,
+        years: Option<u32>,
+        owner: Option<String>,
+        metadata: Option<Metadata>,
+        #[serde(default)]
+        associate: bool,
+    },
     /// Change the admin that manages the whitelist
     /// Will be set to null after go-to-market
     UpdateAdmin { admin: Option<String> },
@@ -35,6 +65,10 @@ pub enum ExecuteMsg {
     RemoveWhitelist { address: String },
     /// Update config, only callable by admin
     UpdateConfig { config: Config },
+    /// Block or reserve names, only callable by admin
+    AddReservedNames { names: Vec<ReservedName> },
+    /// Release blocked or reserved names, only callable by admin
+    RemoveReservedNames { names: Vec<String> },
 }
 
 #[cw_serde]
@@ -44,6 +78,10 @@ pub enum SudoMsg {
         max_name_length: u32,
         base_price: Uint128,
         fair_burn_bps: u64,
+        /// Keeps the current maximum when not set
+        max_years: Option<u32>,
+        /// Keeps the current discounts when not set
+        multi_year_discounts: Option<Vec<MultiYearDiscount>>,
     },
     UpdateNameCollection {
         collection: String,
@@ -51,6 +89,17 @@ pub enum SudoMsg {
     UpdateNameMarketplace {
         marketplace: String,
     },
+    UpdateCommitRevealParams {
+        min_age: u64,
+        max_age: u64,
+        required: bool,
+    },
+    AddReservedNames {
+        names: Vec<ReservedName>,
+    },
+    RemoveReservedNames {
+        names: Vec<String>,
+    },
 }
 
 #[cw_serde]
@@ -66,4 +115,13 @@ pub enum QueryMsg {
     Params {},
     #[returns(Config)]
     Config {},
+    #[returns(CommitRevealParams)]
+    CommitRevealParams {},
+    #[returns(Option<Commitment>)]
+    Commitment { commitment: Binary },
+    #[returns(Vec<(String, Reservation)>)]
+    ReservedNames {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
@@ -1,13 +1,20 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, StdResult};
-use sg_name_minter::{Config, SudoParams};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, Order, StdResult};
+use cw_storage_plus::Bound;
+use sg_name_minter::{Config, Reservation, SudoParams};
 
 use crate::{
     msg::QueryMsg,
-    state::{ADMIN, CONFIG, NAME_COLLECTION, SUDO_PARAMS, WHITELISTS},
+    state::{
+        CommitRevealParams, Commitment, ADMIN, COMMITMENTS, COMMIT_REVEAL_PARAMS, CONFIG,
+        NAME_COLLECTION, RESERVED_NAMES, SUDO_PARAMS, WHITELISTS,
+    },
 };
 
+const DEFAULT_QUERY_LIMIT: u32 = 10;
+const MAX_QUERY_LIMIT: u32 = 100;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -16,6 +23,11 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
         QueryMsg::Whitelists {} => to_json_binary(&query_whitelists(deps)?),
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::CommitRevealParams {} => to_json_binary(&query_commit_reveal_params(deps)?),
+        QueryMsg::Commitment { commitment } => to_json_binary(&query_commitment(deps, commitment)?),
+        QueryMsg::ReservedNames { start_after, limit } => {
+            to_json_binary(&query_reserved_names(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -35,3 +47,25 @@ fn query_params(deps: Deps) -> StdResult<SudoParams> {
 fn query_config(deps: Deps) -> StdResult<Config> {
     CONFIG.load(deps.storage)
 }
+
+fn query_commit_reveal_params(deps: Deps) -> StdResult<CommitRevealParams> {
+    COMMIT_REVEAL_PARAMS.load(deps.storage)
+}
+
+fn query_commitment(deps: Deps, commitment: Binary) -> StdResult<Option<Commitment>> {
+    COMMITMENTS.may_load(deps.storage, &commitment)
+}
+
+fn query_reserved_names(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(String, Reservation)>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    RESERVED_NAMES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
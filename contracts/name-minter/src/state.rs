@@ -1,9 +1,10 @@
-use cosmwasm_std::Addr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, Timestamp};
 use cw_controllers::Admin;
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 
 use serde::{Deserialize, Serialize};
-use sg_name_minter::{Config, SudoParams};
+use sg_name_minter::{Config, Reservation, SudoParams};
 
 #[derive(Serialize, Deserialize)]
 pub struct WhitelistContract {
@@ -32,3 +33,32 @@ pub const WHITELISTS: Item<Vec<WhitelistContract>> = Item::new("whitelists");
 pub const PAUSED: Item<bool> = Item::new("paused");
 
 pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Managed by admin and sudo
+pub const RESERVED_NAMES: Map<&str, Reservation> = Map::new("reserved-names");
+
+#[cw_serde]
+pub struct CommitRevealParams {
+    /// Seconds a commitment has to wait before it can be revealed
+    pub min_age: u64,
+    /// Seconds after which a commitment expires
+    pub max_age: u64,
+    /// When set, names can only be minted with `RevealMint`, off by default.
+    /// `BatchMintAndList` is unavailable while it is set.
+    pub required: bool,
+}
+
+pub const COMMIT_REVEAL_PARAMS: Item<CommitRevealParams> = Item::new("commit-reveal-params");
+
+#[cw_serde]
+pub struct Commitment {
+    /// Sender of the commitment, the only one who can reveal it
+    pub owner: Addr,
+    pub created_at: Timestamp,
+}
+
+/// commitment hash -> commitment
+pub const COMMITMENTS: Map<&[u8], Commitment> = Map::new("commitments");
+
+/// (creation time in seconds, commitment hash) for pruning expired commitments
+pub const COMMITMENTS_BY_TIME: Map<(u64, &[u8]), Empty> = Map::new("commitments-by-time");
@@ -1,12 +1,15 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{Addr, Decimal, DepsMut, Env, Event, Uint128};
-use sg_name_minter::SudoParams;
+use sg_name_minter::{MultiYearDiscount, ReservedName, SudoParams};
 use sg_std::Response;
 
 use crate::{
+    contract::{add_reserved_names, remove_reserved_names},
     msg::SudoMsg,
-    state::{NAME_COLLECTION, NAME_MARKETPLACE, SUDO_PARAMS},
+    state::{
+        CommitRevealParams, COMMIT_REVEAL_PARAMS, NAME_COLLECTION, NAME_MARKETPLACE, SUDO_PARAMS,
+    },
     ContractError,
 };
 
@@ -20,12 +23,16 @@ pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, Contract
             max_name_length,
             base_price,
             fair_burn_bps,
+            max_years,
+            multi_year_discounts,
         } => sudo_update_params(
             deps,
             min_name_length,
             max_name_length,
             base_price,
             fair_burn_bps,
+            max_years,
+            multi_year_discounts,
         ),
         SudoMsg::UpdateNameCollection { collection } => {
             sudo_update_name_collection(deps, api.addr_validate(&collection)?)
@@ -33,6 +40,13 @@ pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, Contract
         SudoMsg::UpdateNameMarketplace { marketplace } => {
             sudo_update_name_marketplace(deps, api.addr_validate(&marketplace)?)
         }
+        SudoMsg::UpdateCommitRevealParams {
+            min_age,
+            max_age,
+            required,
+        } => sudo_update_commit_reveal_params(deps, min_age, max_age, required),
+        SudoMsg::AddReservedNames { names } => sudo_add_reserved_names(deps, names),
+        SudoMsg::RemoveReservedNames { names } => sudo_remove_reserved_names(deps, names),
     }
 }
 
@@ -42,7 +56,19 @@ pub fn sudo_update_params(
     max_name_length: u32,
     base_price: Uint128,
     fair_burn_bps: u64,
+    max_years: Option<u32>,
+    multi_year_discounts: Option<Vec<MultiYearDiscount>>,
 ) -> Result<Response, ContractError> {
+    let params = SUDO_PARAMS.load(deps.storage)?;
+    let max_years = max_years.or(params.max_years);
+    let multi_year_discounts = multi_year_discounts.unwrap_or(params.multi_year_discounts);
+    if multi_year_discounts
+        .iter()
+        .any(|d| d.discount >= Decimal::one())
+    {
+        return Err(ContractError::InvalidMultiYearDiscount {});
+    }
+
     SUDO_PARAMS.save(
         deps.storage,
         &SudoParams {
@@ -50,6 +76,8 @@ pub fn sudo_update_params(
             max_name_length,
             base_price,
             fair_burn_percent: Decimal::percent(fair_burn_bps) / Uint128::from(100u128),
+            max_years,
+            multi_year_discounts,
         },
     )?;
 
@@ -75,3 +103,45 @@ pub fn sudo_update_name_marketplace(
     let event = Event::new("update-name-marketplace").add_attribute("marketplace", marketplace);
     Ok(Response::new().add_event(event))
 }
+
+pub fn sudo_update_commit_reveal_params(
+    deps: DepsMut,
+    min_age: u64,
+    max_age: u64,
+    required: bool,
+) -> Result<Response, ContractError> {
+    if min_age >= max_age {
+        return Err(ContractError::InvalidCommitRevealParams {});
+    }
+
+    COMMIT_REVEAL_PARAMS.save(
+        deps.storage,
+        &CommitRevealParams {
+            min_age,
+            max_age,
+            required,
+        },
+    )?;
+
+    let event = Event::new("update-commit-reveal-params")
+        .add_attribute("min_age", min_age.to_string())
+        .add_attribute("max_age", max_age.to_string())
+        .add_attribute("required", required.to_string());
+    Ok(Response::new().add_event(event))
+}
+
+pub fn sudo_add_reserved_names(
+    deps: DepsMut,
+    names: Vec<ReservedName>,
+) -> Result<Response, ContractError> {
+    let event = add_reserved_names(deps, names)?;
+    Ok(Response::new().add_event(event))
+}
+
+pub fn sudo_remove_reserved_names(
+    deps: DepsMut,
+    names: Vec<String>,
+) -> Result<Response, ContractError> {
+    let event = remove_reserved_names(deps, names);
+    Ok(Response::new().add_event(event))
+}
@@ -1,44 +1,106 @@
 use crate::{
     error::ContractError,
-    state::{SudoParams, NAME_MARKETPLACE, REVERSE_MAP, SUDO_PARAMS, VERIFIER},
+    msg::{
+        AssociationResponse, ChainAddressAttestation, ImageNftStatusResponse, ManagerResponse,
+        PubKeyAttestation, TextRecordAttestation, VerifierResponse,
+    },
+    state::{
+        Attestor, KeyType, SudoParams, ADDRESS_HISTORY, ALIASES, ALL_FUSES, ATTESTORS,
+        CANNOT_CREATE_SUBNAMES, CANNOT_SET_ADDRESS, CANNOT_SET_RECORDS, CANNOT_TRANSFER,
+        CHAIN_REVERSE_MAP, DEFAULT_MAX_HISTORY_COUNT, FUSES, MANAGERS, NAME_HISTORY,
+        NAME_MARKETPLACE, PRIMARY_NAME, RECORDS, RECORDS_MIGRATION, RECORD_INDEX, REVERSE_MAP,
+        SUBNAMES, SUDO_PARAMS, VERIFIED_RECORDS, VERIFIERS,
+    },
 };
 
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, Binary, ContractInfoResponse, Deps, DepsMut, Env, Event,
-    MessageInfo, StdError, StdResult, WasmMsg,
+    ensure, to_json_binary, to_json_vec, Addr, Binary, ContractInfoResponse, Deps, DepsMut, Empty,
+    Env, Event, MessageInfo, Order, StdError, StdResult, Storage, Timestamp, WasmMsg,
 };
 
+use cw721::{AllNftInfoResponse, Cw721Query, Cw721QueryMsg, NftInfoResponse, OwnerOfResponse};
 use cw721_base::{state::TokenInfo, MintMsg};
+use cw_storage_plus::Bound;
 use cw_utils::{nonpayable, Expiration};
 
 use sg721::ExecuteMsg as Sg721ExecuteMsg;
 use sg721_base::msg::CollectionInfoResponse;
 use sg721_base::ContractError::{Claimed, Unauthorized};
-use sg_name::{Metadata, TextRecord, MAX_TEXT_LENGTH, NFT};
+use sg_name::{
+    ChainAddress, ChainAddressProof, ContentCodec, ContentHash, KeyAlgo, Metadata, PubKey,
+    RecordOp, TextRecord, MAX_TEXT_LENGTH, NFT,
+};
 use sg_name_market::SgNameMarketplaceExecuteMsg;
 use sg_std::Response;
 
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 use subtle_encoding::bech32;
 
 pub type Sg721NameContract<'a> = sg721_base::Sg721Contract<'a, Metadata>;
 
+// Query limits
+const DEFAULT_QUERY_LIMIT: u32 = 10;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+// Scope of verifiers that can verify any record and manage other verifiers
+pub const ALL_RECORDS: &str = "*";
+
+// Same as the max name length enforced by the name minter
+const MAX_SUBNAME_LABEL_LENGTH: usize = 63;
+
+// Subnames are removed with their parent when it is sold or burned, so there can't be too many
+pub const MAX_SUBNAMES: usize = 20;
+
+// Chain used in `ChainAddress` for EVM (hex) addresses
+const EVM_CHAIN: &str = "eth";
+
+// Max number of aliases followed when resolving a name
+const MAX_ALIAS_HOPS: u32 = 5;
+
+// Tokens visited per batch when moving text records out of the token extensions
+pub const RECORDS_MIGRATION_BATCH: u32 = 100;
+
 pub fn execute_associate_address(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     name: String,
     address: Option<String>,
 ) -> Result<Response, ContractError> {
-    only_owner(deps.as_ref(), &info.sender, &name)?;
+    let owner = only_owner_or_manager(deps.as_ref(), &env, &info.sender, &name)?;
+    ensure_not_fused(deps.storage, &name, CANNOT_SET_ADDRESS)?;
+
+    associate_address(deps, &env, &owner, &name, address.clone())?;
+
+    let mut event = Event::new("associate-address")
+        .add_attribute("name", name)
+        .add_attribute("owner", info.sender);
+
+    if let Some(address) = address {
+        event = event.add_attribute("address", address);
+    }
+
+    Ok(Response::new().add_event(event))
+}
 
+fn associate_address(
+    deps: DepsMut,
+    env: &Env,
+    owner: &Addr,
+    name: &str,
+    address: Option<String>,
+) -> Result<(), ContractError> {
     // 1. remove old token_uri from reverse map if it exists
-    Sg721NameContract::default()
+    let prev_address = Sg721NameContract::default()
         .tokens
-        .load(deps.storage, &name)
-        .map(|prev_token_info| {
-            if let Some(address) = prev_token_info.token_uri {
-                REVERSE_MAP.remove(deps.storage, &Addr::unchecked(address));
-            }
-        })?;
+        .load(deps.storage, name)?
+        .token_uri
+        .map(Addr::unchecked);
+    if let Some(address) = &prev_address {
+        REVERSE_MAP.remove(deps.storage, address);
+    }
 
     // 2. validate the new address
     let token_uri = address
@@ -46,7 +108,7 @@ pub fn execute_associate_address(
         .map(|address| {
             deps.api
                 .addr_validate(&address)
-                .map(|addr| validate_address(deps.as_ref(), &info.sender, addr))?
+                .map(|addr| validate_address(deps.as_ref(), owner, addr))?
         })
         .transpose()?;
 
@@ -54,14 +116,19 @@ pub fn execute_associate_address(
     let old_name = token_uri
         .clone()
         .and_then(|addr| REVERSE_MAP.may_load(deps.storage, &addr).unwrap_or(None));
+    if let Some(old_name) = old_name.as_deref().filter(|old_name| *old_name != name) {
+        ensure_not_fused(deps.storage, old_name, CANNOT_SET_ADDRESS)?;
+    }
 
     // 4. remove old token_uri / address from previous name
-    old_name.map(|token_id| {
+    old_name.as_ref().map(|token_id| {
         Sg721NameContract::default()
             .tokens
-            .update(deps.storage, &token_id, |token| match token {
+            .update(deps.storage, token_id, |token| match token {
                 Some(mut token_info) => {
                     token_info.token_uri = None;
+                    // keys were proven by the address that is moving away
+                    token_info.extension.pub_keys.clear();
                     Ok(token_info)
                 }
                 None => Err(ContractError::NameNotFound {}),
@@ -71,30 +138,90 @@ pub fn execute_associate_address(
     // 5. associate new token_uri / address with new name / token_id
     Sg721NameContract::default()
         .tokens
-        .update(deps.storage, &name, |token| match token {
+        .update(deps.storage, name, |token| match token {
             Some(mut token_info) => {
-                token_info.token_uri = token_uri.clone().map(|addr| addr.to_string());
+                let token_uri = token_uri.clone().map(|addr| addr.to_string());
+                if token_info.token_uri != token_uri {
+                    token_info.extension.pub_keys.clear();
+                }
+                token_info.token_uri = token_uri;
                 Ok(token_info)
             }
             None => Err(ContractError::NameNotFound {}),
         })?;
 
     // 6. save new reverse map entry
-    token_uri.map(|addr| REVERSE_MAP.save(deps.storage, &addr, &name));
+    token_uri
+        .clone()
+        .map(|addr| REVERSE_MAP.save(deps.storage, &addr, &name.to_string()));
 
-    let mut event = Event::new("associate-address")
-        .add_attribute("name", name)
-        .add_attribute("owner", info.sender);
+    // 7. record the change in the association history
+    let height = env.block.height;
+    if let Some(old_name) = old_name.filter(|old_name| old_name != name) {
+        save_name_history(deps.storage, &old_name, height, None)?;
+    }
+    save_name_history(deps.storage, name, height, token_uri.clone())?;
+    if let Some(prev_address) = prev_address.filter(|prev| Some(prev) != token_uri.as_ref()) {
+        save_address_history(deps.storage, &prev_address, height, None)?;
+    }
+    if let Some(addr) = &token_uri {
+        save_address_history(deps.storage, addr, height, Some(name.to_string()))?;
+    }
 
-    if let Some(address) = address {
-        event = event.add_attribute("address", address);
+    Ok(())
+}
+
+fn max_history_count(storage: &dyn Storage) -> StdResult<usize> {
+    Ok(SUDO_PARAMS
+        .load(storage)?
+        .max_history_count
+        .unwrap_or(DEFAULT_MAX_HISTORY_COUNT) as usize)
+}
+
+// Keeps the latest `max_history_count` changes of a name
+fn save_name_history(
+    storage: &mut dyn Storage,
+    name: &str,
+    height: u64,
+    address: Option<Addr>,
+) -> StdResult<()> {
+    NAME_HISTORY.save(storage, (name, height), &address)?;
+
+    let heights = NAME_HISTORY
+        .prefix(name)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let excess = heights.len().saturating_sub(max_history_count(storage)?);
+    for height in heights.into_iter().take(excess) {
+        NAME_HISTORY.remove(storage, (name, height));
     }
+    Ok(())
+}
 
-    Ok(Response::new().add_event(event))
+// Keeps the latest `max_history_count` changes of an address
+fn save_address_history(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    height: u64,
+    name: Option<String>,
+) -> StdResult<()> {
+    ADDRESS_HISTORY.save(storage, (address, height), &name)?;
+
+    let heights = ADDRESS_HISTORY
+        .prefix(address)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let excess = heights.len().saturating_sub(max_history_count(storage)?);
+    for height in heights.into_iter().take(excess) {
+        ADDRESS_HISTORY.remove(storage, (address, height));
+    }
+    Ok(())
 }
 
+/// Mint a name with initial metadata, `token_uri` is associated with the name if set
 pub fn execute_mint(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     msg: MintMsg<Metadata>,
 ) -> Result<Response, ContractError> {
@@ -102,12 +229,26 @@ pub fn execute_mint(
     if info.sender != minter {
         return Err(ContractError::Base(Unauthorized {}));
     }
-    // create the token
+    let owner = deps.api.addr_validate(&msg.owner)?;
+
+    // create the token, records are stored apart from the extension
+    let mut extension = msg.extension;
+    let records = std::mem::take(&mut extension.records)
+        .into_iter()
+        .map(|mut record| {
+            record.verified = None;
+            record.verified_at = None;
+            record.value = normalize_record_value(&record.name, &record.value);
+            record
+        })
+        .collect::<Vec<_>>();
+    validate_mint_metadata(deps.as_ref(), &owner, &extension, &records)?;
+
     let token = TokenInfo {
-        owner: deps.api.addr_validate(&msg.owner)?,
+        owner: owner.clone(),
         approvals: vec![],
         token_uri: None,
-        extension: msg.extension,
+        extension,
     };
     Sg721NameContract::default()
         .tokens
@@ -115,6 +256,11 @@ pub fn execute_mint(
             Some(_) => Err(ContractError::Base(Claimed {})),
             None => Ok(token),
         })?;
+    save_records(deps.storage, &msg.token_id, records)?;
+
+    if let Some(address) = msg.token_uri {
+        associate_address(deps.branch(), &env, &owner, &msg.token_id, Some(address))?;
+    }
 
     Sg721NameContract::default().increment_tokens(deps.storage)?;
 
@@ -125,8 +271,43 @@ pub fn execute_mint(
     Ok(Response::new().add_event(event))
 }
 
+// Metadata set at mint goes through the same checks as updates by the owner
+fn validate_mint_metadata(
+    deps: Deps,
+    owner: &Addr,
+    extension: &Metadata,
+    records: &[TextRecord],
+) -> Result<(), ContractError> {
+    // chain addresses and keys need proofs that only their owner can give
+    ensure!(
+        extension.addresses.is_empty() && extension.pub_keys.is_empty(),
+        ContractError::UnsupportedMintMetadata {}
+    );
+    if let Some(nft) = &extension.image_nft {
+        validate_image_nft(deps, owner, nft)?;
+    }
+    if let Some(content_hash) = &extension.content_hash {
+        validate_content_hash_bytes(content_hash)?;
+    }
+
+    let max_record_count = SUDO_PARAMS.load(deps.storage)?.max_record_count;
+    if records.len() > max_record_count as usize {
+        return Err(ContractError::TooManyRecords {
+            max: max_record_count,
+        });
+    }
+    for (i, record) in records.iter().enumerate() {
+        validate_record(record)?;
+        ensure!(
+            !records[..i].iter().any(|r| r.name == record.name),
+            ContractError::RecordNameAlreadyExists {}
+        );
+    }
+    Ok(())
+}
+
 pub fn execute_burn(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     token_id: String,
@@ -137,6 +318,14 @@ pub fn execute_burn(
 
     ensure!(info.sender == names_marketplace, Unauthorized {});
 
+    // subnames can not outlive their parent
+    remove_subnames(&mut deps, &env, &token_id)?;
+    // fuses do not carry over to a name minted again after a burn
+    FUSES.remove(deps.storage, &token_id);
+    ALIASES.remove(deps.storage, &token_id);
+    // clear reverse lookups pointing at the burned name
+    reset_token_metadata_and_reverse_map(&mut deps, &env, &token_id)?;
+
     let sg721 = Sg721NameContract::default();
 
     // Force names marketplace address as operator
@@ -172,23 +361,69 @@ pub fn execute_transfer_nft(
     let names_marketplace = NAME_MARKETPLACE.load(deps.storage)?;
     let recipient = deps.api.addr_validate(&recipient)?;
 
-    let update_ask_msg =
-        _transfer_nft(deps, env, &info, &recipient, &token_id, &names_marketplace)?;
+    let update_ask_msg = _transfer_nft(
+        deps,
+        env,
+        &info,
+        &recipient,
+        &token_id,
+        &names_marketplace,
+        false,
+    )?;
 
     let event = Event::new("transfer")
         .add_attribute("sender", info.sender)
         .add_attribute("recipient", recipient)
         .add_attribute("token_id", token_id);
 
-    Ok(Response::new().add_message(update_ask_msg).add_event(event))
+    Ok(Response::new()
+        .add_messages(update_ask_msg)
+        .add_event(event))
+}
+
+pub fn execute_transfer_with_metadata(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let names_marketplace = NAME_MARKETPLACE.load(deps.storage)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    let update_ask_msg = _transfer_nft(
+        deps,
+        env,
+        &info,
+        &recipient,
+        &token_id,
+        &names_marketplace,
+        true,
+    )?;
+
+    let event = Event::new("transfer-with-metadata")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("token_id", token_id);
+
+    Ok(Response::new()
+        .add_messages(update_ask_msg)
+        .add_event(event))
 }
 
 // Update the ask on the marketplace
+// Subnames are not listed on the marketplace, so they have no ask to update
 fn update_ask_on_marketplace(
     deps: Deps,
     token_id: &str,
     recipient: Addr,
-) -> Result<WasmMsg, ContractError> {
+) -> Result<Option<WasmMsg>, ContractError> {
+    if parent_name(token_id).is_some() {
+        return Ok(None);
+    }
+
     let msg = SgNameMarketplaceExecuteMsg::UpdateAsk {
         token_id: token_id.to_string(),
         seller: recipient.to_string(),
@@ -198,34 +433,127 @@ fn update_ask_on_marketplace(
         funds: vec![],
         msg: to_json_binary(&msg)?,
     };
-    Ok(update_ask_msg)
+    Ok(Some(update_ask_msg))
 }
 
-fn reset_token_metadata_and_reverse_map(deps: &mut DepsMut, token_id: &str) -> StdResult<()> {
+// Fused parts of the metadata are kept
+fn reset_token_metadata_and_reverse_map(
+    deps: &mut DepsMut,
+    env: &Env,
+    token_id: &str,
+) -> StdResult<()> {
+    // fused records of a token the migration hasn't reached are kept too
+    migrate_token_records(deps.storage, token_id)?;
     let mut token = Sg721NameContract::default()
         .tokens
         .load(deps.storage, token_id)?;
+    let fuses = fuses_of(deps.storage, token_id)?;
+    let address_fused = fuses & CANNOT_SET_ADDRESS != 0;
+    let records_fused = fuses & CANNOT_SET_RECORDS != 0;
+
+    if !address_fused {
+        for address in token.extension.addresses.iter() {
+            CHAIN_REVERSE_MAP.remove(deps.storage, (&address.chain, &address.address));
+        }
+    }
+
+    remove_owner_links(deps, token_id, &token.owner)?;
+    if !records_fused {
+        remove_records(deps.storage, token_id)?;
+    }
+    if !address_fused && !records_fused {
+        ALIASES.remove(deps.storage, token_id);
+    }
 
-    // Reset image, records
-    token.extension = Metadata::default();
+    // Reset image, records, chain addresses
+    let old = std::mem::take(&mut token.extension);
+    if address_fused {
+        token.extension.addresses = old.addresses;
+        token.extension.pub_keys = old.pub_keys;
+    }
+    if records_fused {
+        token.extension.image_nft = old.image_nft;
+        token.extension.content_hash = old.content_hash;
+    }
     Sg721NameContract::default()
         .tokens
         .save(deps.storage, token_id, &token)?;
 
-    remove_reverse_mapping(deps, token_id)?;
+    if !address_fused {
+        remove_reverse_mapping(deps, env, token_id)?;
+    }
+
+    Ok(())
+}
+
+// Keeps records and image, but drops verifications
+// and whatever the previous owner granted, associated or proved
+fn strip_token_for_transfer(deps: &mut DepsMut, env: &Env, token_id: &str) -> StdResult<()> {
+    let mut token = Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, token_id)?;
+
+    remove_owner_links(deps, token_id, &token.owner)?;
+
+    // the association is removed, so are the keys and chain addresses it proved
+    let address_fused = fuses_of(deps.storage, token_id)? & CANNOT_SET_ADDRESS != 0;
+    if !address_fused {
+        for address in std::mem::take(&mut token.extension.addresses) {
+            CHAIN_REVERSE_MAP.remove(deps.storage, (&address.chain, &address.address));
+        }
+        token.extension.pub_keys.clear();
+        Sg721NameContract::default()
+            .tokens
+            .save(deps.storage, token_id, &token)?;
+    }
+
+    for mut record in load_records(deps.storage, token_id)? {
+        if record.verified.is_some() {
+            record.verified = None;
+            record.verified_at = None;
+            save_record(deps.storage, token_id, &record)?;
+        }
+    }
+
+    if !address_fused {
+        remove_reverse_mapping(deps, env, token_id)?;
+    }
+
+    Ok(())
+}
+
+// Removes managers and the primary name set by the owner
+fn remove_owner_links(deps: &mut DepsMut, token_id: &str, owner: &Addr) -> StdResult<()> {
+    let managers = MANAGERS
+        .prefix(token_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for manager in managers {
+        MANAGERS.remove(deps.storage, (token_id, &manager));
+    }
+
+    if PRIMARY_NAME.may_load(deps.storage, owner)?.as_deref() == Some(token_id) {
+        PRIMARY_NAME.remove(deps.storage, owner);
+    }
 
     Ok(())
 }
 
-fn remove_reverse_mapping(deps: &mut DepsMut, token_id: &str) -> StdResult<()> {
+// Removes the association of a name, which is recorded in the history
+fn remove_reverse_mapping(deps: &mut DepsMut, env: &Env, token_id: &str) -> StdResult<()> {
     let mut token = Sg721NameContract::default()
         .tokens
         .load(deps.storage, token_id)?;
 
     // remove reverse mapping if exists
     if let Some(token_uri) = token.token_uri {
-        REVERSE_MAP.remove(deps.storage, &Addr::unchecked(token_uri));
+        let address = Addr::unchecked(token_uri);
+        REVERSE_MAP.remove(deps.storage, &address);
         token.token_uri = None;
+
+        let height = env.block.height;
+        save_name_history(deps.storage, token_id, height, None)?;
+        save_address_history(deps.storage, &address, height, None)?;
     }
 
     Sg721NameContract::default()
@@ -242,10 +570,26 @@ fn _transfer_nft(
     recipient: &Addr,
     token_id: &str,
     names_marketplace: &Addr,
-) -> Result<WasmMsg, ContractError> {
+    preserve_metadata: bool,
+) -> Result<Option<WasmMsg>, ContractError> {
+    // fused names can still be sold
+    if info.sender != *names_marketplace {
+        ensure_not_fused(deps.storage, token_id, CANNOT_TRANSFER)?;
+    }
+
     let update_ask_msg = update_ask_on_marketplace(deps.as_ref(), token_id, recipient.clone())?;
 
-    reset_token_metadata_and_reverse_map(&mut deps, token_id)?;
+    // sales always reset the metadata
+    if preserve_metadata && info.sender != *names_marketplace {
+        strip_token_for_transfer(&mut deps, &env, token_id)?;
+    } else {
+        reset_token_metadata_and_reverse_map(&mut deps, &env, token_id)?;
+    }
+
+    // a transfer by the marketplace is a sale, which invalidates subnames
+    if info.sender == *names_marketplace {
+        remove_subnames(&mut deps, &env, token_id)?;
+    }
 
     let msg = Sg721ExecuteMsg::TransferNft {
         recipient: recipient.to_string(),
@@ -274,11 +618,13 @@ pub fn execute_send_nft(
     token_id: String,
     msg: Binary,
 ) -> Result<Response, ContractError> {
+    ensure_not_fused(deps.storage, &token_id, CANNOT_TRANSFER)?;
+
     let contract_addr = deps.api.addr_validate(&contract)?;
     let update_ask_msg =
         update_ask_on_marketplace(deps.as_ref(), &token_id, contract_addr.clone())?;
 
-    reset_token_metadata_and_reverse_map(&mut deps, &token_id)?;
+    reset_token_metadata_and_reverse_map(&mut deps, &env, &token_id)?;
 
     let msg = Sg721ExecuteMsg::SendNft {
         contract: contract_addr.to_string(),
@@ -293,196 +639,1364 @@ pub fn execute_send_nft(
         .add_attribute("contract", contract_addr.to_string())
         .add_attribute("token_id", token_id);
 
-    Ok(Response::new().add_message(update_ask_msg).add_event(event))
+    Ok(Response::new()
+        .add_messages(update_ask_msg)
+        .add_event(event))
 }
 
-pub fn execute_update_image_nft(
+pub fn execute_set_primary_name(
     deps: DepsMut,
     info: MessageInfo,
-    name: String,
-    nft: Option<NFT>,
+    name: Option<String>,
 ) -> Result<Response, ContractError> {
-    let token_id = name.clone();
-
     nonpayable(&info)?;
-    only_owner(deps.as_ref(), &info.sender, &token_id)?;
-
-    let mut event = Event::new("update_image_nft")
-        .add_attribute("owner", info.sender.to_string())
-        .add_attribute("token_id", name);
 
-    Sg721NameContract::default()
-        .tokens
-        .update(deps.storage, &token_id, |token| match token {
-            Some(mut token_info) => {
-                token_info.extension.image_nft = nft.clone();
-                Ok(token_info)
-            }
-            None => Err(ContractError::NameNotFound {}),
-        })?;
+    let mut event = Event::new("set-primary-name").add_attribute("owner", info.sender.to_string());
 
-    if let Some(nft) = nft {
-        event = event.add_attribute("image_nft", nft.into_json_string());
+    match name {
+        Some(name) => {
+            only_owner(deps.as_ref(), &info.sender, &name)?;
+            PRIMARY_NAME.save(deps.storage, &info.sender, &name)?;
+            event = event.add_attribute("name", name);
+        }
+        None => PRIMARY_NAME.remove(deps.storage, &info.sender),
     }
 
     Ok(Response::new().add_event(event))
 }
 
-pub fn execute_add_text_record(
+pub fn execute_add_manager(
     deps: DepsMut,
     info: MessageInfo,
     name: String,
-    mut record: TextRecord,
+    manager: String,
+    expires: Option<Expiration>,
 ) -> Result<Response, ContractError> {
-    let token_id = name;
-    let params = SUDO_PARAMS.load(deps.storage)?;
-    let max_record_count = params.max_record_count;
-
-    // new records should reset verified to None
-    record.verified = None;
-
     nonpayable(&info)?;
-    only_owner(deps.as_ref(), &info.sender, &token_id)?;
-    validate_record(&record)?;
+    only_owner(deps.as_ref(), &info.sender, &name)?;
 
-    Sg721NameContract::default()
-        .tokens
-        .update(deps.storage, &token_id, |token| match token {
-            Some(mut token_info) => {
-                // can not add a record with existing name
-                for r in token_info.extension.records.iter() {
-                    if r.name == record.name {
-                        return Err(ContractError::RecordNameAlreadyExists {});
-                    }
-                }
-                token_info.extension.records.push(record.clone());
-                // check record length
-                if token_info.extension.records.len() > max_record_count as usize {
-                    return Err(ContractError::TooManyRecords {
-                        max: max_record_count,
-                    });
-                }
-                Ok(token_info)
-            }
-            None => Err(ContractError::NameNotFound {}),
-        })?;
+    let manager = deps.api.addr_validate(&manager)?;
+    let expires = expires.unwrap_or_default();
+    MANAGERS.save(deps.storage, (&name, &manager), &expires)?;
 
-    let event = Event::new("add-text-record")
+    let event = Event::new("add-manager")
         .add_attribute("sender", info.sender)
-        .add_attribute("name", token_id)
-        .add_attribute("record", record.into_json_string());
+        .add_attribute("name", name)
+        .add_attribute("manager", manager)
+        .add_attribute("expires", expires.to_string());
     Ok(Response::new().add_event(event))
 }
 
-pub fn execute_remove_text_record(
+pub fn execute_remove_manager(
     deps: DepsMut,
     info: MessageInfo,
     name: String,
-    record_name: String,
+    manager: String,
 ) -> Result<Response, ContractError> {
-    let token_id = name;
-
     nonpayable(&info)?;
-    only_owner(deps.as_ref(), &info.sender, &token_id)?;
+    only_owner(deps.as_ref(), &info.sender, &name)?;
 
-    Sg721NameContract::default()
-        .tokens
-        .update(deps.storage, &token_id, |token| match token {
-            Some(mut token_info) => {
-                token_info
-                    .extension
-                    .records
-                    .retain(|r| r.name != record_name);
-                Ok(token_info)
-            }
-            None => Err(ContractError::NameNotFound {}),
-        })?;
+    let manager = deps.api.addr_validate(&manager)?;
+    MANAGERS.remove(deps.storage, (&name, &manager));
 
-    let event = Event::new("remove-text-record")
+    let event = Event::new("remove-manager")
         .add_attribute("sender", info.sender)
-        .add_attribute("name", token_id)
-        .add_attribute("record_name", record_name);
+        .add_attribute("name", name)
+        .add_attribute("manager", manager);
     Ok(Response::new().add_event(event))
 }
 
-pub fn execute_update_text_record(
+pub fn execute_set_alias(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     name: String,
-    mut record: TextRecord,
+    target: Option<String>,
 ) -> Result<Response, ContractError> {
-    let token_id = name;
-    let params = SUDO_PARAMS.load(deps.storage)?;
-    let max_record_count = params.max_record_count;
-
-    // updated records should reset verified to None
-    record.verified = None;
-
     nonpayable(&info)?;
-    only_owner(deps.as_ref(), &info.sender, &token_id)?;
-    validate_record(&record)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &name)?;
+    // an alias replaces both the address and the records of a name
+    ensure_not_fused(deps.storage, &name, CANNOT_SET_ADDRESS | CANNOT_SET_RECORDS)?;
 
-    Sg721NameContract::default()
-        .tokens
-        .update(deps.storage, &token_id, |token| match token {
-            Some(mut token_info) => {
-                token_info
-                    .extension
-                    .records
-                    .retain(|r| r.name != record.name);
-                token_info.extension.records.push(record.clone());
-                // check record length
-                if token_info.extension.records.len() > max_record_count as usize {
-                    return Err(ContractError::TooManyRecords {
-                        max: max_record_count,
-                    });
-                }
-                Ok(token_info)
+    let mut event = Event::new("set-alias")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", name.clone());
+
+    match target {
+        Some(target) => {
+            ensure!(
+                Sg721NameContract::default()
+                    .tokens
+                    .has(deps.storage, &target),
+                ContractError::NameNotFound {}
+            );
+            // the target chain must not lead back to the alias
+            let mut hops = 1;
+            let mut next = Some(target.clone());
+            while let Some(current) = next {
+                ensure!(current != name, ContractError::AliasCycle {});
+                ensure!(
+                    hops <= MAX_ALIAS_HOPS,
+                    ContractError::TooManyAliasHops {
+                        max: MAX_ALIAS_HOPS
+                    }
+                );
+                next = ALIASES.may_load(deps.storage, &current)?;
+                hops += 1;
             }
-            None => Err(ContractError::NameNotFound {}),
-        })?;
+            ALIASES.save(deps.storage, &name, &target)?;
+            event = event.add_attribute("target", target);
+        }
+        None => ALIASES.remove(deps.storage, &name),
+    }
 
-    let event = Event::new("update-text-record")
-        .add_attribute("sender", info.sender)
-        .add_attribute("name", token_id)
-        .add_attribute("record", record.into_json_string());
     Ok(Response::new().add_event(event))
 }
 
-pub fn execute_verify_text_record(
+// Follows aliases to the name that holds the address and records
+fn resolve_alias(storage: &dyn Storage, name: &str) -> StdResult<String> {
+    let mut name = name.to_string();
+    for _ in 0..=MAX_ALIAS_HOPS {
+        match ALIASES.may_load(storage, &name)? {
+            Some(target) => name = target,
+            None => return Ok(name),
+        }
+    }
+    // targets can add aliases of their own after the alias was set
+    Err(StdError::generic_err(format!(
+        "More than {} alias hops",
+        MAX_ALIAS_HOPS
+    )))
+}
+
+pub fn execute_burn_fuses(
     deps: DepsMut,
     info: MessageInfo,
     name: String,
-    record_name: String,
-    result: bool,
+    fuses: u32,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
-    VERIFIER.assert_admin(deps.as_ref(), &info.sender)?;
+    only_owner(deps.as_ref(), &info.sender, &name)?;
+    ensure!(
+        fuses != 0 && fuses & !ALL_FUSES == 0,
+        ContractError::InvalidFuses {}
+    );
 
-    let token_id = name;
+    // fuses can only be added, never removed
+    let fuses = fuses_of(deps.storage, &name)? | fuses;
+    FUSES.save(deps.storage, &name, &fuses)?;
 
-    Sg721NameContract::default()
-        .tokens
+    let event = Event::new("burn-fuses")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", name)
+        .add_attribute("fuses", fuses.to_string());
+    Ok(Response::new().add_event(event))
+}
+
+fn fuses_of(storage: &dyn Storage, token_id: &str) -> StdResult<u32> {
+    Ok(FUSES.may_load(storage, token_id)?.unwrap_or_default())
+}
+
+fn ensure_not_fused(storage: &dyn Storage, token_id: &str, fuse: u32) -> Result<(), ContractError> {
+    ensure!(
+        fuses_of(storage, token_id)? & fuse == 0,
+        ContractError::FuseBurned {}
+    );
+    Ok(())
+}
+
+pub fn execute_create_subname(
+    deps: DepsMut,
+    info: MessageInfo,
+    parent: String,
+    label: String,
+    owner: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    // only one level of subnames is supported
+    ensure!(
+        parent_name(&parent).is_none(),
+        ContractError::InvalidSubname {}
+    );
+    only_owner(deps.as_ref(), &info.sender, &parent)?;
+    ensure_not_fused(deps.storage, &parent, CANNOT_CREATE_SUBNAMES)?;
+    validate_subname_label(&label)?;
+    let count = SUBNAMES
+        .prefix(&parent)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(MAX_SUBNAMES)
+        .count();
+    ensure!(count < MAX_SUBNAMES, ContractError::TooManySubnames {});
+
+    let token_id = format!("{}.{}", label, parent);
+    let token = TokenInfo {
+        owner: deps.api.addr_validate(&owner)?,
+        approvals: vec![],
+        token_uri: None,
+        extension: Metadata::default(),
+    };
+    Sg721NameContract::default()
+        .tokens
+        .update(deps.storage, &token_id, |old| match old {
+            Some(_) => Err(ContractError::Base(Claimed {})),
+            None => Ok(token),
+        })?;
+
+    Sg721NameContract::default().increment_tokens(deps.storage)?;
+
+    SUBNAMES.save(deps.storage, (&parent, &token_id), &Empty {})?;
+
+    let event = Event::new("create-subname")
+        .add_attribute("sender", info.sender)
+        .add_attribute("parent", parent)
+        .add_attribute("name", token_id)
+        .add_attribute("owner", owner);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_transfer_subname(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let parent = parent_name(&name).ok_or(ContractError::NotASubname {})?;
+    only_owner(deps.as_ref(), &info.sender, parent)?;
+
+    ensure_not_fused(deps.storage, &name, CANNOT_TRANSFER)?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    reset_token_metadata_and_reverse_map(&mut deps, &env, &name)?;
+
+    Sg721NameContract::default()
+        .tokens
+        .update(deps.storage, &name, |token| match token {
+            Some(mut token_info) => {
+                token_info.owner = recipient.clone();
+                token_info.approvals = vec![];
+                Ok(token_info)
+            }
+            None => Err(ContractError::NameNotFound {}),
+        })?;
+
+    let event = Event::new("transfer-subname")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", name)
+        .add_attribute("recipient", recipient);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_revoke_subname(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let parent = parent_name(&name).ok_or(ContractError::NotASubname {})?;
+    only_owner(deps.as_ref(), &info.sender, parent)?;
+    // fuses are a promise of the subname owner that the parent can't break
+    ensure!(
+        fuses_of(deps.storage, &name)? == 0,
+        ContractError::FuseBurned {}
+    );
+
+    remove_subname(&mut deps, &env, parent, &name)?;
+
+    let event = Event::new("revoke-subname")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", name);
+    Ok(Response::new().add_event(event))
+}
+
+fn remove_subname(deps: &mut DepsMut, env: &Env, parent: &str, name: &str) -> StdResult<()> {
+    // also makes sure the subname exists before the token count is decremented
+    ALIASES.remove(deps.storage, name);
+    reset_token_metadata_and_reverse_map(deps, env, name)?;
+
+    let sg721 = Sg721NameContract::default();
+    sg721.tokens.remove(deps.storage, name)?;
+    sg721.decrement_tokens(deps.storage)?;
+
+    SUBNAMES.remove(deps.storage, (parent, name));
+
+    Ok(())
+}
+
+// Subnames with burned fuses outlive their parent
+fn remove_subnames(deps: &mut DepsMut, env: &Env, parent: &str) -> StdResult<()> {
+    let subnames = SUBNAMES
+        .prefix(parent)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for subname in subnames {
+        if fuses_of(deps.storage, &subname)? == 0 {
+            remove_subname(deps, env, parent, &subname)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn execute_update_image_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    nft: Option<NFT>,
+) -> Result<Response, ContractError> {
+    let token_id = name.clone();
+
+    nonpayable(&info)?;
+    let owner = only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_RECORDS)?;
+    if let Some(nft) = &nft {
+        validate_image_nft(deps.as_ref(), &owner, nft)?;
+    }
+
+    let mut event = Event::new("update_image_nft")
+        .add_attribute("owner", info.sender.to_string())
+        .add_attribute("token_id", name);
+
+    Sg721NameContract::default()
+        .tokens
         .update(deps.storage, &token_id, |token| match token {
             Some(mut token_info) => {
-                if let Some(r) = token_info
+                token_info.extension.image_nft = nft.clone();
+                Ok(token_info)
+            }
+            None => Err(ContractError::NameNotFound {}),
+        })?;
+
+    if let Some(nft) = nft {
+        event = event.add_attribute("image_nft", nft.into_json_string());
+    }
+
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_update_content_hash(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    content_hash: Option<ContentHash>,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_RECORDS)?;
+    if let Some(content_hash) = &content_hash {
+        validate_content_hash_bytes(content_hash)?;
+    }
+
+    let token_info = Sg721NameContract::default().tokens.update(
+        deps.storage,
+        &token_id,
+        |token| match token {
+            Some(mut token_info) => {
+                token_info.extension.content_hash = content_hash;
+                Ok(token_info)
+            }
+            None => Err(ContractError::NameNotFound {}),
+        },
+    )?;
+    let mut metadata = token_info.extension;
+    metadata.records = load_records(deps.storage, &token_id)?;
+
+    // gateways index this event to resolve names to websites
+    let event = Event::new("update-content-hash")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("metadata", metadata.into_json_string());
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_set_pub_key(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    pub_key: PubKey,
+    signer: Binary,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_ADDRESS)?;
+    validate_pub_key(&pub_key)?;
+
+    let mut token_info = Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, &token_id)?;
+    let address = token_info
+        .token_uri
+        .clone()
+        .ok_or(ContractError::NoAssociatedAddress {})?;
+
+    // the signer key must belong to the associated address
+    let (_, address_bytes) =
+        bech32::decode(&address).map_err(|_| ContractError::InvalidPubKeyProof {})?;
+    ensure!(
+        Ripemd160::digest(Sha256::digest(&signer))[..] == address_bytes[..],
+        ContractError::InvalidPubKeyProof {}
+    );
+
+    let message = to_json_vec(&PubKeyAttestation {
+        collection: env.contract.address.to_string(),
+        name: token_id.clone(),
+        address: address.clone(),
+        pub_key: pub_key.clone(),
+    })?;
+    // malformed signatures are treated as invalid
+    let valid = deps
+        .api
+        .secp256k1_verify(&Sha256::digest(&message), &signature, &signer)
+        .unwrap_or(false);
+    ensure!(valid, ContractError::InvalidPubKeyProof {});
+
+    let pub_keys = &mut token_info.extension.pub_keys;
+    pub_keys.retain(|k| k.algo != pub_key.algo);
+    pub_keys.push(pub_key.clone());
+    Sg721NameContract::default()
+        .tokens
+        .save(deps.storage, &token_id, &token_info)?;
+
+    let event = Event::new("set-pub-key")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("address", address)
+        .add_attribute("pub_key", pub_key.key.to_base64());
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_remove_pub_key(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    algo: KeyAlgo,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_ADDRESS)?;
+
+    Sg721NameContract::default()
+        .tokens
+        .update(deps.storage, &token_id, |token| match token {
+            Some(mut token_info) => {
+                let pub_keys = &mut token_info.extension.pub_keys;
+                ensure!(
+                    pub_keys.iter().any(|k| k.algo == algo),
+                    ContractError::PubKeyNotFound {}
+                );
+                pub_keys.retain(|k| k.algo != algo);
+                Ok(token_info)
+            }
+            None => Err(ContractError::NameNotFound {}),
+        })?;
+
+    let event = Event::new("remove-pub-key")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id);
+    Ok(Response::new().add_event(event))
+}
+
+// x25519 keys are 32 bytes, secp256k1 keys are compressed (33 bytes)
+fn validate_pub_key(pub_key: &PubKey) -> Result<(), ContractError> {
+    let len = match pub_key.algo {
+        KeyAlgo::X25519 => 32,
+        KeyAlgo::Secp256k1 => 33,
+    };
+    ensure!(pub_key.key.len() == len, ContractError::InvalidPubKey {});
+    Ok(())
+}
+
+pub fn execute_add_text_record(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    mut record: TextRecord,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+    let params = SUDO_PARAMS.load(deps.storage)?;
+    let max_record_count = params.max_record_count;
+
+    // new records should reset verified to None
+    record.verified = None;
+    record.verified_at = None;
+    record.value = normalize_record_value(&record.name, &record.value);
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_RECORDS)?;
+    validate_record(&record)?;
+
+    // can not add a record with existing name
+    ensure!(
+        find_record(deps.as_ref(), &token_id, &record.name)?.is_none(),
+        ContractError::RecordNameAlreadyExists {}
+    );
+    // check record length
+    if record_count(deps.storage, &token_id)? >= max_record_count as usize {
+        return Err(ContractError::TooManyRecords {
+            max: max_record_count,
+        });
+    }
+    save_record(deps.storage, &token_id, &record)?;
+
+    let event = Event::new("add-text-record")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("record", record.into_json_string());
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_remove_text_record(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    record_name: String,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_RECORDS)?;
+
+    if let Some(old) = find_record(deps.as_ref(), &token_id, &record_name)? {
+        remove_record(deps.storage, &token_id, &old)?;
+    }
+
+    let event = Event::new("remove-text-record")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("record_name", record_name);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_update_text_record(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    mut record: TextRecord,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+    let params = SUDO_PARAMS.load(deps.storage)?;
+    let max_record_count = params.max_record_count;
+
+    // updated records should reset verified to None
+    record.verified = None;
+    record.verified_at = None;
+    record.value = normalize_record_value(&record.name, &record.value);
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_RECORDS)?;
+    validate_record(&record)?;
+
+    match find_record(deps.as_ref(), &token_id, &record.name)? {
+        Some(old) => remove_record(deps.storage, &token_id, &old)?,
+        // check record length
+        None => {
+            if record_count(deps.storage, &token_id)? >= max_record_count as usize {
+                return Err(ContractError::TooManyRecords {
+                    max: max_record_count,
+                });
+            }
+        }
+    }
+    save_record(deps.storage, &token_id, &record)?;
+
+    let event = Event::new("update-text-record")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("record", record.into_json_string());
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_batch_update(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    ops: Vec<RecordOp>,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+    let params = SUDO_PARAMS.load(deps.storage)?;
+    let max_record_count = params.max_record_count;
+
+    nonpayable(&info)?;
+    let owner = only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+
+    // the token is saved below, so its legacy records are moved first
+    migrate_token_records(deps.storage, &token_id)?;
+    let mut token_info = Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, &token_id)
+        .map_err(|_| ContractError::NameNotFound {})?;
+    let old_records = load_records(deps.storage, &token_id)?;
+    let mut records = old_records.clone();
+
+    let mut event = Event::new("batch-update")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id.clone());
+    let mut address = None;
+
+    for op in ops {
+        let fuse = match op {
+            RecordOp::AssociateAddress { .. } => CANNOT_SET_ADDRESS,
+            _ => CANNOT_SET_RECORDS,
+        };
+        ensure_not_fused(deps.storage, &token_id, fuse)?;
+
+        match op {
+            RecordOp::AddTextRecord { mut record } => {
+                if records.iter().any(|r| r.name == record.name) {
+                    return Err(ContractError::RecordNameAlreadyExists {});
+                }
+                record.verified = None;
+                record.verified_at = None;
+                record.value = normalize_record_value(&record.name, &record.value);
+                validate_record(&record)?;
+                event = event.add_attribute("add_record", record.into_json_string());
+                records.push(record);
+            }
+            RecordOp::UpdateTextRecord { mut record } => {
+                record.verified = None;
+                record.verified_at = None;
+                record.value = normalize_record_value(&record.name, &record.value);
+                validate_record(&record)?;
+                records.retain(|r| r.name != record.name);
+                event = event.add_attribute("update_record", record.into_json_string());
+                records.push(record);
+            }
+            RecordOp::RemoveTextRecord { record_name } => {
+                records.retain(|r| r.name != record_name);
+                event = event.add_attribute("remove_record", record_name);
+            }
+            RecordOp::UpdateImageNft { nft } => {
+                if let Some(nft) = &nft {
+                    validate_image_nft(deps.as_ref(), &owner, nft)?;
+                }
+                event = event.add_attribute(
+                    "image_nft",
+                    nft.as_ref()
+                        .map_or(String::new(), |nft| nft.into_json_string()),
+                );
+                token_info.extension.image_nft = nft;
+            }
+            RecordOp::AssociateAddress {
+                address: new_address,
+            } => {
+                event = event.add_attribute("address", new_address.clone().unwrap_or_default());
+                address = Some(new_address);
+            }
+        }
+    }
+
+    // the record count is checked once all operations are applied
+    if records.len() > max_record_count as usize {
+        return Err(ContractError::TooManyRecords {
+            max: max_record_count,
+        });
+    }
+
+    for record in old_records.iter() {
+        if !records.contains(record) {
+            remove_record(deps.storage, &token_id, record)?;
+        }
+    }
+    for record in records.iter() {
+        if !old_records.contains(record) {
+            save_record(deps.storage, &token_id, record)?;
+        }
+    }
+
+    Sg721NameContract::default()
+        .tokens
+        .save(deps.storage, &token_id, &token_info)?;
+
+    if let Some(address) = address {
+        associate_address(deps.branch(), &env, &owner, &token_id, address)?;
+    }
+
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_add_chain_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    address: ChainAddress,
+    proof: Option<ChainAddressProof>,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+    let params = SUDO_PARAMS.load(deps.storage)?;
+    let max_record_count = params.max_record_count;
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_ADDRESS)?;
+    let address = validate_chain_address(address)?;
+    validate_chain_address_proof(deps.as_ref(), &env, &token_id, &address, proof.as_ref())?;
+
+    Sg721NameContract::default()
+        .tokens
+        .update(deps.storage, &token_id, |token| match token {
+            Some(mut token_info) => {
+                // can not add a second address for the same chain
+                if token_info
                     .extension
-                    .records
-                    .iter_mut()
-                    .find(|r| r.name == record_name)
+                    .addresses
+                    .iter()
+                    .any(|a| a.chain == address.chain)
                 {
-                    r.verified = Some(result);
+                    return Err(ContractError::ChainAddressAlreadyExists {});
+                }
+                token_info.extension.addresses.push(address.clone());
+                if token_info.extension.addresses.len() > max_record_count as usize {
+                    return Err(ContractError::TooManyRecords {
+                        max: max_record_count,
+                    });
+                }
+                Ok(token_info)
+            }
+            None => Err(ContractError::NameNotFound {}),
+        })?;
+
+    save_chain_reverse_mapping(deps.storage, &address, &token_id)?;
+
+    let event = Event::new("add-chain-address")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("address", address.into_json_string());
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_remove_chain_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    chain: String,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+    let mut removed = None;
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_ADDRESS)?;
+
+    Sg721NameContract::default()
+        .tokens
+        .update(deps.storage, &token_id, |token| match token {
+            Some(mut token_info) => {
+                removed = token_info
+                    .extension
+                    .addresses
+                    .iter()
+                    .find(|a| a.chain == chain)
+                    .cloned();
+                token_info.extension.addresses.retain(|a| a.chain != chain);
+                Ok(token_info)
+            }
+            None => Err(ContractError::NameNotFound {}),
+        })?;
+
+    if let Some(address) = removed {
+        CHAIN_REVERSE_MAP.remove(deps.storage, (&address.chain, &address.address));
+    }
+
+    let event = Event::new("remove-chain-address")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("chain", chain);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_update_chain_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    address: ChainAddress,
+    proof: Option<ChainAddressProof>,
+) -> Result<Response, ContractError> {
+    let token_id = name;
+    let params = SUDO_PARAMS.load(deps.storage)?;
+    let max_record_count = params.max_record_count;
+    let mut replaced = None;
+
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &token_id)?;
+    ensure_not_fused(deps.storage, &token_id, CANNOT_SET_ADDRESS)?;
+    let address = validate_chain_address(address)?;
+    validate_chain_address_proof(deps.as_ref(), &env, &token_id, &address, proof.as_ref())?;
+
+    Sg721NameContract::default()
+        .tokens
+        .update(deps.storage, &token_id, |token| match token {
+            Some(mut token_info) => {
+                replaced = token_info
+                    .extension
+                    .addresses
+                    .iter()
+                    .find(|a| a.chain == address.chain)
+                    .cloned();
+                token_info
+                    .extension
+                    .addresses
+                    .retain(|a| a.chain != address.chain);
+                token_info.extension.addresses.push(address.clone());
+                if token_info.extension.addresses.len() > max_record_count as usize {
+                    return Err(ContractError::TooManyRecords {
+                        max: max_record_count,
+                    });
                 }
                 Ok(token_info)
             }
             None => Err(ContractError::NameNotFound {}),
         })?;
 
+    if let Some(old) = replaced {
+        CHAIN_REVERSE_MAP.remove(deps.storage, (&old.chain, &old.address));
+    }
+    save_chain_reverse_mapping(deps.storage, &address, &token_id)?;
+
+    let event = Event::new("update-chain-address")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("address", address.into_json_string());
+    Ok(Response::new().add_event(event))
+}
+
+// Addresses derived from the same key as the owner or the associated address
+// are controlled by the owner, others need a signature from their key
+fn validate_chain_address_proof(
+    deps: Deps,
+    env: &Env,
+    token_id: &str,
+    address: &ChainAddress,
+    proof: Option<&ChainAddressProof>,
+) -> Result<(), ContractError> {
+    let token = Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, token_id)?;
+
+    let key_hash = bech32::decode(&address.address).ok().map(|(_, data)| data);
+    if let Some(key_hash) = &key_hash {
+        let same_key = [Some(token.owner.to_string()), token.token_uri.clone()]
+            .into_iter()
+            .flatten()
+            .any(|addr| bech32::decode(addr).is_ok_and(|(_, data)| &data == key_hash));
+        if same_key {
+            return Ok(());
+        }
+    }
+
+    let proof = proof.ok_or(ContractError::InvalidChainAddressProof {})?;
+    let derived = match key_hash {
+        Some(key_hash) => Ripemd160::digest(Sha256::digest(&proof.pub_key))[..] == key_hash[..],
+        // EVM addresses are the last 20 bytes of the hash of the uncompressed key
+        None => {
+            proof.pub_key.len() == 65
+                && address.address[2..].eq_ignore_ascii_case(&hex_encode(
+                    &Keccak256::digest(&proof.pub_key[1..])[12..],
+                ))
+        }
+    };
+    ensure!(derived, ContractError::InvalidChainAddressProof {});
+
+    // the owner is part of the message so proofs can't be replayed after a transfer
+    let message = to_json_vec(&ChainAddressAttestation {
+        collection: env.contract.address.to_string(),
+        name: token_id.to_string(),
+        owner: token.owner.to_string(),
+        chain: address.chain.clone(),
+        address: address.address.clone(),
+    })?;
+    // malformed signatures are treated as invalid
+    let valid = deps
+        .api
+        .secp256k1_verify(&Sha256::digest(&message), &proof.signature, &proof.pub_key)
+        .unwrap_or(false);
+    ensure!(valid, ContractError::InvalidChainAddressProof {});
+
+    Ok(())
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// An address on another chain can only resolve back to a single name
+fn save_chain_reverse_mapping(
+    storage: &mut dyn Storage,
+    address: &ChainAddress,
+    name: &str,
+) -> Result<(), ContractError> {
+    let key = (address.chain.as_str(), address.address.as_str());
+
+    if let Some(mapped_name) = CHAIN_REVERSE_MAP.may_load(storage, key)? {
+        ensure!(mapped_name == name, ContractError::AddressAlreadyMapped {});
+    }
+    CHAIN_REVERSE_MAP.save(storage, key, &name.to_string())?;
+
+    Ok(())
+}
+
+pub fn execute_verify_text_record(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    record_name: String,
+    result: bool,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure!(
+        is_verifier_for(deps.as_ref(), &info.sender, &record_name)?,
+        ContractError::UnauthorizedVerification {}
+    );
+
+    let token_id = name;
+
+    ensure!(
+        Sg721NameContract::default()
+            .tokens
+            .has(deps.storage, &token_id),
+        ContractError::NameNotFound {}
+    );
+    if let Some(mut record) = find_record(deps.as_ref(), &token_id, &record_name)? {
+        record.verified = Some(result);
+        record.verified_at = Some(env.block.time);
+        index_verification(deps.branch(), &env, &token_id, &record)?;
+        RECORDS.save(deps.storage, (&token_id, &record.name), &record)?;
+    }
+
+    let event = Event::new("verify-text-record")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", token_id)
+        .add_attribute("record", record_name)
+        .add_attribute("result", result.to_string());
+    Ok(Response::new().add_event(event))
+}
+
+// A verified record value can only be claimed by one name
+fn index_verification(
+    deps: DepsMut,
+    env: &Env,
+    token_id: &str,
+    record: &TextRecord,
+) -> Result<(), ContractError> {
+    let verified = record.verified == Some(true);
+    if verified {
+        if let Some(holder) =
+            VERIFIED_RECORDS.may_load(deps.storage, (&record.name, &record.value))?
+        {
+            ensure!(
+                holder == token_id || !is_verified(deps.as_ref(), env, &holder, &record.name)?,
+                ContractError::RecordAlreadyVerified {}
+            );
+        }
+        VERIFIED_RECORDS.save(
+            deps.storage,
+            (&record.name, &record.value),
+            &token_id.to_string(),
+        )?;
+    } else {
+        release_verification(deps.storage, token_id, record)?;
+    }
+
+    RECORD_INDEX.save(
+        deps.storage,
+        (&record.name, &record.value, token_id),
+        &verified,
+    )?;
+    Ok(())
+}
+
+// Frees the verified slot of a record value if the name holds it
+fn release_verification(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    record: &TextRecord,
+) -> StdResult<()> {
+    let key = (record.name.as_str(), record.value.as_str());
+    if VERIFIED_RECORDS.may_load(storage, key)?.as_deref() == Some(token_id) {
+        VERIFIED_RECORDS.remove(storage, key);
+    }
+    Ok(())
+}
+
+fn find_record(deps: Deps, token_id: &str, record_name: &str) -> StdResult<Option<TextRecord>> {
+    match RECORDS.may_load(deps.storage, (token_id, record_name))? {
+        Some(record) => Ok(Some(record)),
+        None => Ok(legacy_records(deps.storage, token_id)?
+            .into_iter()
+            .find(|record| record.name == record_name)),
+    }
+}
+
+/// Text records of a name, ordered by record name
+pub fn load_records(storage: &dyn Storage, token_id: &str) -> StdResult<Vec<TextRecord>> {
+    let mut records = RECORDS
+        .prefix(token_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, record)| record))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let legacy = legacy_records(storage, token_id)?;
+    if !legacy.is_empty() {
+        for record in legacy {
+            if !records.iter().any(|r| r.name == record.name) {
+                records.push(record);
+            }
+        }
+        records.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    Ok(records)
+}
+
+fn record_count(storage: &dyn Storage, token_id: &str) -> StdResult<usize> {
+    Ok(load_records(storage, token_id)?.len())
+}
+
+// Records still in the extension of a token the migration hasn't reached,
+// records set since the upgrade are newer and take precedence.
+// Their values are normalized like new ones so lookups and comparisons find them.
+fn legacy_records(storage: &dyn Storage, token_id: &str) -> StdResult<Vec<TextRecord>> {
+    // tokens up to the cursor are migrated, `None` < `Some(_)` when none are
+    let pending = match RECORDS_MIGRATION.may_load(storage)? {
+        Some(cursor) => cursor.as_deref() < Some(token_id),
+        None => false,
+    };
+    if !pending {
+        return Ok(vec![]);
+    }
+    Ok(Sg721NameContract::default()
+        .tokens
+        .may_load(storage, token_id)?
+        .map(|token| normalize_records(token.extension.records))
+        .unwrap_or_default())
+}
+
+fn normalize_records(records: Vec<TextRecord>) -> Vec<TextRecord> {
+    records
+        .into_iter()
+        .map(|mut record| {
+            record.value = normalize_record_value(&record.name, &record.value);
+            record
+        })
+        .collect()
+}
+
+// Moves the legacy records of a token to the records map before they are changed
+fn migrate_token_records(storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+    if legacy_records(storage, token_id)?.is_empty() {
+        return Ok(());
+    }
+    let token = Sg721NameContract::default()
+        .tokens
+        .load(storage, token_id)?;
+    move_legacy_records(storage, token_id, token)
+}
+
+fn move_legacy_records(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    mut token: TokenInfo<Metadata>,
+) -> StdResult<()> {
+    let records = std::mem::take(&mut token.extension.records);
+    if records.is_empty() {
+        return Ok(());
+    }
+    Sg721NameContract::default()
+        .tokens
+        .save(storage, token_id, &token)?;
+    for record in normalize_records(records) {
+        if !RECORDS.has(storage, (token_id, &record.name)) {
+            write_record(storage, token_id, &record)?;
+        }
+    }
+    Ok(())
+}
+
+fn save_record(storage: &mut dyn Storage, token_id: &str, record: &TextRecord) -> StdResult<()> {
+    migrate_token_records(storage, token_id)?;
+    write_record(storage, token_id, record)
+}
+
+// Saves a record along with its reverse lookup entry
+fn write_record(storage: &mut dyn Storage, token_id: &str, record: &TextRecord) -> StdResult<()> {
+    let verified = record.verified == Some(true);
+    let key = (record.name.as_str(), record.value.as_str());
+    if !verified {
+        release_verification(storage, token_id, record)?;
+    } else if !VERIFIED_RECORDS.has(storage, key) {
+        VERIFIED_RECORDS.save(storage, key, &token_id.to_string())?;
+    }
+    RECORD_INDEX.save(storage, (&record.name, &record.value, token_id), &verified)?;
+    RECORDS.save(storage, (token_id, &record.name), record)
+}
+
+fn remove_record(storage: &mut dyn Storage, token_id: &str, record: &TextRecord) -> StdResult<()> {
+    migrate_token_records(storage, token_id)?;
+    release_verification(storage, token_id, record)?;
+    RECORD_INDEX.remove(storage, (&record.name, &record.value, token_id));
+    RECORDS.remove(storage, (token_id, &record.name));
+    Ok(())
+}
+
+fn remove_records(storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+    for record in load_records(storage, token_id)? {
+        remove_record(storage, token_id, &record)?;
+    }
+    Ok(())
+}
+
+/// Stores records that came in a token extension, at mint or when migrating older tokens
+pub fn save_records(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    records: Vec<TextRecord>,
+) -> StdResult<()> {
+    for record in records {
+        save_record(storage, token_id, &record)?;
+    }
+    Ok(())
+}
+
+// Moves the text records of the next `limit` tokens out of their extension,
+// returns true once all tokens are migrated
+pub fn migrate_records(storage: &mut dyn Storage, limit: u32) -> StdResult<bool> {
+    let Some(start_after) = RECORDS_MIGRATION.may_load(storage)? else {
+        return Ok(true);
+    };
+
+    let sg721 = Sg721NameContract::default();
+    let tokens = sg721
+        .tokens
+        .range(
+            storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    let done = tokens.len() < limit as usize;
+
+    let mut last = start_after;
+    for (token_id, token) in tokens {
+        move_legacy_records(storage, &token_id, token)?;
+        last = Some(token_id);
+    }
+
+    if done {
+        RECORDS_MIGRATION.remove(storage);
+    } else {
+        RECORDS_MIGRATION.save(storage, &last)?;
+    }
+    Ok(done)
+}
+
+pub fn execute_migrate_records(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    ensure!(
+        RECORDS_MIGRATION.may_load(deps.storage)?.is_some(),
+        ContractError::RecordsMigrated {}
+    );
+
+    let done = migrate_records(deps.storage, limit.unwrap_or(RECORDS_MIGRATION_BATCH))?;
+
+    let event = Event::new("migrate-records")
+        .add_attribute("sender", info.sender)
+        .add_attribute("done", done.to_string());
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_request_verification(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    record_name: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_owner_or_manager(deps.as_ref(), &env, &info.sender, &name)?;
+
+    let record =
+        find_record(deps.as_ref(), &name, &record_name)?.ok_or(ContractError::RecordNotFound {})?;
+
+    // oracles index this event to know which records to check
+    let event = Event::new("request-verification")
+        .add_attribute("sender", info.sender)
+        .add_attribute("name", name)
+        .add_attribute("record", record.name)
+        .add_attribute("value", record.value);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_add_verifier(
+    deps: DepsMut,
+    info: MessageInfo,
+    verifier: String,
+    scope: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_root_verifier(deps.as_ref(), &info.sender)?;
+    ensure!(!scope.is_empty(), ContractError::InvalidVerifierScope {});
+
+    let verifier = deps.api.addr_validate(&verifier)?;
+    VERIFIERS.save(deps.storage, (&scope, &verifier), &Empty {})?;
+
+    let event = Event::new("add-verifier")
+        .add_attribute("sender", info.sender)
+        .add_attribute("verifier", verifier)
+        .add_attribute("scope", scope);
+    Ok(Response::new().add_event(event))
+}
+
+pub fn execute_remove_verifier(
+    deps: DepsMut,
+    info: MessageInfo,
+    verifier: String,
+    scope: String,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    only_root_verifier(deps.as_ref(), &info.sender)?;
+
+    let verifier = deps.api.addr_validate(&verifier)?;
+    ensure!(
+        VERIFIERS.has(deps.storage, (&scope, &verifier)),
+        ContractError::VerifierNotFound {}
+    );
+    VERIFIERS.remove(deps.storage, (&scope, &verifier));
+
+    let event = Event::new("remove-verifier")
+        .add_attribute("sender", info.sender)
+        .add_attribute("verifier", verifier)
+        .add_attribute("scope", scope);
+    Ok(Response::new().add_event(event))
+}
+
+fn only_root_verifier(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    ensure!(
+        VERIFIERS.has(deps.storage, (ALL_RECORDS, sender)),
+        ContractError::UnauthorizedVerification {}
+    );
+    Ok(())
+}
+
+// A scope matches a record name exactly, or by prefix when it ends with `*`
+fn scope_matches(scope: &str, record_name: &str) -> bool {
+    match scope.strip_suffix('*') {
+        Some(prefix) => record_name.starts_with(prefix),
+        None => scope == record_name,
+    }
+}
+
+fn is_verifier_for(deps: Deps, sender: &Addr, record_name: &str) -> StdResult<bool> {
+    for item in VERIFIERS.keys(deps.storage, None, None, Order::Ascending) {
+        let (scope, verifier) = item?;
+        if verifier == sender && scope_matches(&scope, record_name) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_verify_text_record_with_signature(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    record: TextRecord,
+    issued_at: Timestamp,
+    pub_key: Binary,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    // old signatures could otherwise be replayed to verify a record again
+    ensure!(
+        issued_at <= env.block.time,
+        ContractError::InvalidAttestation {}
+    );
+    if let Some(period) = SUDO_PARAMS.load(deps.storage)?.verification_period {
+        ensure!(
+            issued_at.plus_seconds(period) > env.block.time,
+            ContractError::AttestationExpired {}
+        );
+    }
+
+    let key_type = ATTESTORS
+        .may_load(deps.storage, (&record.name, pub_key.as_slice()))?
+        .ok_or(ContractError::UnknownAttestor {})?;
+
+    let token_id = name;
+    let token_info = Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, &token_id)
+        .map_err(|_| ContractError::NameNotFound {})?;
+
+    // the owner is part of the message so proofs can't be replayed after a transfer
+    let message = to_json_vec(&TextRecordAttestation {
+        collection: env.contract.address.to_string(),
+        name: token_id.clone(),
+        owner: token_info.owner.to_string(),
+        record_name: record.name.clone(),
+        value: record.value.clone(),
+        issued_at,
+    })?;
+    // malformed signatures are treated as invalid
+    let valid = match key_type {
+        KeyType::Secp256k1 => {
+            deps.api
+                .secp256k1_verify(&Sha256::digest(&message), &signature, &pub_key)
+        }
+        KeyType::Ed25519 => deps.api.ed25519_verify(&message, &signature, &pub_key),
+    }
+    .unwrap_or(false);
+    ensure!(valid, ContractError::InvalidAttestation {});
+
+    let mut stored = find_record(deps.as_ref(), &token_id, &record.name)?
+        .filter(|r| r.value == normalize_record_value(&record.name, &record.value))
+        .ok_or(ContractError::RecordNotFound {})?;
+    stored.verified = Some(true);
+    stored.verified_at = Some(issued_at);
+    index_verification(deps.branch(), &env, &token_id, &stored)?;
+    RECORDS.save(deps.storage, (&token_id, &stored.name), &stored)?;
+
     let event = Event::new("verify-text-record")
         .add_attribute("sender", info.sender)
         .add_attribute("name", token_id)
-        .add_attribute("record", record_name)
-        .add_attribute("result", result.to_string());
+        .add_attribute("record", record.name)
+        .add_attribute("result", true.to_string())
+        .add_attribute("attestor", pub_key.to_base64());
     Ok(Response::new().add_event(event))
 }
 
@@ -506,6 +2020,28 @@ pub fn execute_set_name_marketplace(
     Ok(Response::new().add_event(event))
 }
 
+// Managers can update records on behalf of the owner until they expire
+fn only_owner_or_manager(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    token_id: &str,
+) -> Result<Addr, ContractError> {
+    let owner = Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, token_id)?
+        .owner;
+
+    if owner == sender {
+        return Ok(owner);
+    }
+
+    match MANAGERS.may_load(deps.storage, (token_id, sender))? {
+        Some(expires) if !expires.is_expired(&env.block) => Ok(owner),
+        _ => Err(ContractError::Base(Unauthorized {})),
+    }
+}
+
 fn only_owner(deps: Deps, sender: &Addr, token_id: &str) -> Result<Addr, ContractError> {
     let owner = Sg721NameContract::default()
         .tokens
@@ -519,8 +2055,86 @@ fn only_owner(deps: Deps, sender: &Addr, token_id: &str) -> Result<Addr, Contrac
     Ok(owner)
 }
 
+/// Returns the parent of a subname (i.e: `alice` for `pay.alice`)
+pub fn parent_name(name: &str) -> Option<&str> {
+    name.split_once('.').map(|(_, parent)| parent)
+}
+
+// Subname labels follow the same rules as names, except for the min length
+fn validate_subname_label(label: &str) -> Result<(), ContractError> {
+    let len = label.len();
+    if len == 0 || len > MAX_SUBNAME_LABEL_LENGTH {
+        return Err(ContractError::InvalidSubname {});
+    }
+
+    let is_valid_char = |c: char| c.is_ascii_digit() || c.is_ascii_lowercase() || c == '-';
+    if !label.chars().all(is_valid_char) || label.starts_with('-') || label.ends_with('-') {
+        return Err(ContractError::InvalidSubname {});
+    }
+
+    Ok(())
+}
+
+// Returns the chain of an address as used in `ChainAddress`,
+// which is the bech32 prefix, or `eth` for EVM addresses
+fn address_chain(address: &str) -> Option<String> {
+    let is_evm_address = address.len() == 42
+        && address.starts_with("0x")
+        && address[2..].chars().all(|c| c.is_ascii_hexdigit());
+    if is_evm_address {
+        return Some(EVM_CHAIN.to_string());
+    }
+
+    bech32::decode(address).ok().map(|(prefix, _)| prefix)
+}
+
+// Returns the address as it is stored, EVM addresses are lowercased
+// so checksummed and lowercase forms map to the same name
+fn validate_chain_address(mut address: ChainAddress) -> Result<ChainAddress, ContractError> {
+    // stars addresses are set with `AssociateAddress`
+    if address.chain == "stars" {
+        return Err(ContractError::InvalidChainAddress {});
+    }
+
+    if address_chain(&address.address).as_deref() != Some(address.chain.as_str()) {
+        return Err(ContractError::InvalidChainAddress {});
+    }
+
+    if address.chain == EVM_CHAIN {
+        address.address = address.address.to_lowercase();
+    }
+    Ok(address)
+}
+
+fn validate_image_nft(deps: Deps, owner: &Addr, nft: &NFT) -> Result<(), ContractError> {
+    ensure!(
+        image_nft_owner(deps, nft)? == *owner,
+        ContractError::ImageNftNotOwned {}
+    );
+    Ok(())
+}
+
+fn image_nft_owner(deps: Deps, nft: &NFT) -> StdResult<String> {
+    let res: OwnerOfResponse = deps.querier.query_wasm_smart(
+        &nft.collection,
+        &Cw721QueryMsg::OwnerOf {
+            token_id: nft.token_id.clone(),
+            include_expired: None,
+        },
+    )?;
+    Ok(res.owner)
+}
+
+// Handles are case insensitive, they are stored lowercase so each one has a single value
+fn normalize_record_value(record_name: &str, value: &str) -> String {
+    match record_name {
+        "twitter" | "github" | "discord" | "telegram" => value.to_lowercase(),
+        _ => value.to_string(),
+    }
+}
+
 fn validate_record(record: &TextRecord) -> Result<(), ContractError> {
-    if record.verified.is_some() {
+    if record.verified.is_some() || record.verified_at.is_some() {
         return Err(ContractError::UnauthorizedVerification {});
     }
     let name_len = record.name.len();
@@ -535,21 +2149,182 @@ fn validate_record(record: &TextRecord) -> Result<(), ContractError> {
     } else if record.value.is_empty() {
         return Err(ContractError::RecordValueEmpty {});
     }
+
+    validate_record_value(&record.name, &record.value)
+}
+
+// Well-known record names have their value format checked, other records are free text
+fn validate_record_value(record_name: &str, value: &str) -> Result<(), ContractError> {
+    match record_name {
+        "url" => ensure!(
+            is_url(value, &["https://", "http://"]),
+            ContractError::InvalidUrl {}
+        ),
+        "avatar" => ensure!(
+            is_url(value, &["https://", "http://", "ipfs://"]),
+            ContractError::InvalidAvatar {}
+        ),
+        "email" => ensure!(is_email(value), ContractError::InvalidEmail {}),
+        "twitter" => ensure!(
+            is_handle(value, 1..=15, &['_']),
+            ContractError::InvalidHandle {}
+        ),
+        // dashes can not start or end a GitHub username
+        "github" => ensure!(
+            is_handle(value, 1..=39, &['-']) && !value.starts_with('-') && !value.ends_with('-'),
+            ContractError::InvalidHandle {}
+        ),
+        // legacy Discord usernames end with a 4 digit discriminator
+        "discord" => ensure!(
+            match value.split_once('#') {
+                Some((username, tag)) => {
+                    is_handle(username, 2..=32, &['_', '.'])
+                        && tag.len() == 4
+                        && tag.chars().all(|c| c.is_ascii_digit())
+                }
+                None => is_handle(value, 2..=32, &['_', '.']),
+            },
+            ContractError::InvalidHandle {}
+        ),
+        "telegram" => ensure!(
+            is_handle(value, 5..=32, &['_']),
+            ContractError::InvalidHandle {}
+        ),
+        "pubkey" => ensure!(is_pub_key(value), ContractError::InvalidPubKey {}),
+        "contenthash" => validate_content_hash(value)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+fn is_url(value: &str, schemes: &[&str]) -> bool {
+    schemes.iter().any(|scheme| {
+        value.strip_prefix(scheme).is_some_and(|rest| {
+            !rest.is_empty() && !rest.starts_with('/') && !rest.contains(char::is_whitespace)
+        })
+    })
+}
+
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !value.contains(char::is_whitespace)
+        && domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+fn is_handle(value: &str, len: std::ops::RangeInclusive<usize>, extra_chars: &[char]) -> bool {
+    len.contains(&value.len())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || extra_chars.contains(&c))
+}
+
+// Base64 encoded secp256k1 (compressed) or ed25519 public key
+fn is_pub_key(value: &str) -> bool {
+    Binary::from_base64(value).is_ok_and(|key| key.len() == 33 || key.len() == 32)
+}
+
+/// A content hash is hex encoded (EIP-1577), or an IPFS multihash (`Qm...`)
+pub fn validate_content_hash(value: &str) -> Result<(), ContractError> {
+    const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    let hex = value.strip_prefix("0x").unwrap_or(value);
+    let is_hex =
+        !hex.is_empty() && hex.len() & 1 == 0 && hex.chars().all(|c| c.is_ascii_hexdigit());
+    let is_multihash = value.len() == 46
+        && value.starts_with("Qm")
+        && value.chars().all(|c| BASE58_ALPHABET.contains(c));
+
+    ensure!(is_hex || is_multihash, ContractError::InvalidContentHash {});
+    Ok(())
+}
+
+// A multihash is `<hash function code><digest length><digest>` with varint encoded code and length.
+// Arweave content is addressed by 32 byte transaction ids instead.
+fn validate_content_hash_bytes(content_hash: &ContentHash) -> Result<(), ContractError> {
+    let hash = content_hash.hash.as_slice();
+    let valid = match content_hash.codec {
+        ContentCodec::Arweave => hash.len() == 32,
+        ContentCodec::Ipfs | ContentCodec::Ipns | ContentCodec::Swarm => read_varint(hash)
+            .and_then(|(_, rest)| read_varint(rest))
+            .is_some_and(|(len, digest)| len > 0 && len == digest.len() as u64),
+    };
+
+    ensure!(valid, ContractError::InvalidContentHash {});
     Ok(())
 }
 
+// Unsigned LEB128 varint as used by multiformats, returns the value and the remaining bytes
+fn read_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    // multiformats varints are at most 9 bytes
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[i + 1..]));
+        }
+    }
+    None
+}
+
 pub fn query_name_marketplace(deps: Deps) -> StdResult<Addr> {
     NAME_MARKETPLACE.load(deps.storage)
 }
 
-pub fn query_name(deps: Deps, mut address: String) -> StdResult<String> {
-    if !address.starts_with("stars") {
-        address = transcode(&address)?;
+pub fn query_name(deps: Deps, address: String) -> StdResult<String> {
+    name_of(deps, &address)?.ok_or_else(|| {
+        StdError::generic_err(format!("No name associated with address {}", address))
+    })
+}
+
+pub fn query_names(deps: Deps, addresses: Vec<String>) -> StdResult<Vec<(String, Option<String>)>> {
+    ensure_batch_size(addresses.len())?;
+
+    // an invalid address doesn't fail the whole batch
+    Ok(addresses
+        .into_iter()
+        .map(|address| {
+            let name = name_of(deps, &address).ok().flatten();
+            (address, name)
+        })
+        .collect())
+}
+
+fn name_of(deps: Deps, address: &str) -> StdResult<Option<String>> {
+    // addresses set for a specific chain take precedence over the stars mapping
+    if let Some(chain) = address_chain(address) {
+        let key = if chain == EVM_CHAIN {
+            address.to_lowercase()
+        } else {
+            address.to_string()
+        };
+        if let Some(name) = CHAIN_REVERSE_MAP.may_load(deps.storage, (&chain, &key))? {
+            return Ok(Some(name));
+        }
     }
 
-    REVERSE_MAP
-        .load(deps.storage, &deps.api.addr_validate(&address)?)
-        .map_err(|_| StdError::generic_err(format!("No name associated with address {}", address)))
+    let address = if address.starts_with("stars") {
+        address.to_string()
+    } else {
+        transcode(address)?
+    };
+
+    REVERSE_MAP.may_load(deps.storage, &deps.api.addr_validate(&address)?)
+}
+
+fn ensure_batch_size(len: usize) -> StdResult<()> {
+    if len > MAX_QUERY_LIMIT as usize {
+        return Err(StdError::generic_err(format!(
+            "Cannot query more than {} entries at once",
+            MAX_QUERY_LIMIT
+        )));
+    }
+    Ok(())
 }
 
 pub fn query_params(deps: Deps) -> StdResult<SudoParams> {
@@ -557,42 +2332,347 @@ pub fn query_params(deps: Deps) -> StdResult<SudoParams> {
 }
 
 pub fn query_associated_address(deps: Deps, name: &str) -> StdResult<String> {
+    let name = resolve_alias(deps.storage, name)?;
     Sg721NameContract::default()
         .tokens
-        .load(deps.storage, name)?
+        .load(deps.storage, &name)?
         .token_uri
         .ok_or_else(|| StdError::generic_err("No associated address"))
 }
 
-pub fn query_image_nft(deps: Deps, name: &str) -> StdResult<Option<NFT>> {
+pub fn query_associated_addresses(
+    deps: Deps,
+    names: Vec<String>,
+) -> StdResult<Vec<(String, Option<String>)>> {
+    ensure_batch_size(names.len())?;
+
+    // a name that can't be resolved doesn't fail the whole batch
+    Ok(names
+        .into_iter()
+        .map(|name| {
+            let address = resolve_alias(deps.storage, &name)
+                .and_then(|target| {
+                    Sg721NameContract::default()
+                        .tokens
+                        .may_load(deps.storage, &target)
+                })
+                .ok()
+                .flatten()
+                .and_then(|token| token.token_uri);
+            (name, address)
+        })
+        .collect())
+}
+
+pub fn query_name_by_record(
+    deps: Deps,
+    env: &Env,
+    record_name: &str,
+    value: &str,
+    verified_only: bool,
+) -> StdResult<String> {
+    let value = normalize_record_value(record_name, value);
+
+    if let Some(name) = VERIFIED_RECORDS.may_load(deps.storage, (record_name, &value))? {
+        if is_verified(deps, env, &name, record_name)? {
+            return Ok(name);
+        }
+    }
+
+    let name = if verified_only {
+        None
+    } else {
+        RECORD_INDEX
+            .prefix((record_name, &value))
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .transpose()?
+    };
+    name.ok_or_else(|| StdError::generic_err(format!("No name for {} {}", record_name, value)))
+}
+
+pub fn query_verifiers(deps: Deps) -> StdResult<Vec<VerifierResponse>> {
+    VERIFIERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(scope, verifier)| VerifierResponse { verifier, scope }))
+        .collect()
+}
+
+pub fn query_attestors(deps: Deps, record_name: &str) -> StdResult<Vec<Attestor>> {
+    ATTESTORS
+        .prefix(record_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(pub_key, key_type)| Attestor {
+                key_type,
+                pub_key: pub_key.into(),
+            })
+        })
+        .collect()
+}
+
+pub fn query_managers(deps: Deps, name: &str) -> StdResult<Vec<ManagerResponse>> {
+    MANAGERS
+        .prefix(name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(manager, expires)| ManagerResponse { manager, expires }))
+        .collect()
+}
+
+pub fn query_subnames(
+    deps: Deps,
+    parent: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<String>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    SUBNAMES
+        .prefix(parent)
+        .keys(
+            deps.storage,
+            start_after.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+pub fn query_primary_name(deps: Deps, address: String) -> StdResult<Option<String>> {
+    let address = deps.api.addr_validate(&address)?;
+
+    match PRIMARY_NAME.may_load(deps.storage, &address)? {
+        Some(name) => Ok(Some(name)),
+        None => REVERSE_MAP.may_load(deps.storage, &address),
+    }
+}
+
+pub fn query_address_for(deps: Deps, name: &str, chain: &str) -> StdResult<String> {
+    if chain == "stars" {
+        return query_associated_address(deps, name);
+    }
+
+    let name = resolve_alias(deps.storage, name)?;
+    Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, &name)?
+        .extension
+        .addresses
+        .into_iter()
+        .find(|a| a.chain == chain)
+        .map(|a| a.address)
+        .ok_or_else(|| StdError::generic_err(format!("No {} address", chain)))
+}
+
+pub fn query_content_hash(deps: Deps, name: &str) -> StdResult<Option<ContentHash>> {
     Ok(Sg721NameContract::default()
         .tokens
         .load(deps.storage, name)?
         .extension
-        .image_nft)
+        .content_hash)
+}
+
+pub fn query_association_history(
+    deps: Deps,
+    name: &str,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<AssociationResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    NAME_HISTORY
+        .prefix(name)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(height, address)| AssociationResponse { height, address }))
+        .collect()
+}
+
+// The latest change at or before the height tells the name at that time
+pub fn query_name_at(deps: Deps, address: String, height: u64) -> StdResult<Option<String>> {
+    let address = deps.api.addr_validate(&address)?;
+
+    Ok(ADDRESS_HISTORY
+        .prefix(&address)
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive(height)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .and_then(|(_, name)| name))
+}
+
+pub fn query_alias_of(deps: Deps, name: &str) -> StdResult<Option<String>> {
+    ALIASES.may_load(deps.storage, name)
+}
+
+pub fn query_fuses(deps: Deps, name: &str) -> StdResult<u32> {
+    // fails for unknown names
+    Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, name)?;
+    fuses_of(deps.storage, name)
 }
 
-pub fn query_text_records(deps: Deps, name: &str) -> StdResult<Vec<TextRecord>> {
+pub fn query_pub_keys(deps: Deps, name: &str) -> StdResult<Vec<PubKey>> {
     Ok(Sg721NameContract::default()
         .tokens
         .load(deps.storage, name)?
         .extension
-        .records)
+        .pub_keys)
 }
-pub fn query_is_twitter_verified(deps: Deps, name: &str) -> StdResult<bool> {
-    let records = Sg721NameContract::default()
+
+pub fn query_image_nft(deps: Deps, name: &str) -> StdResult<Option<NFT>> {
+    let name = resolve_alias(deps.storage, name)?;
+    Ok(Sg721NameContract::default()
         .tokens
-        .load(deps.storage, name)?
+        .load(deps.storage, &name)?
         .extension
-        .records;
+        .image_nft)
+}
 
-    for record in records {
-        if record.name == "twitter" {
-            return Ok(record.verified.unwrap_or(false));
-        }
-    }
+// Verifications older than the verification period are reported as unverified.
+// Records verified before timestamps were tracked are treated as expired once a period is set.
+fn expire_verifications(
+    deps: Deps,
+    env: &Env,
+    records: Vec<TextRecord>,
+) -> StdResult<Vec<TextRecord>> {
+    let Some(period) = SUDO_PARAMS.load(deps.storage)?.verification_period else {
+        return Ok(records);
+    };
 
-    Ok(false)
+    Ok(records
+        .into_iter()
+        .map(|mut record| {
+            let expired = match record.verified_at {
+                Some(at) => at.plus_seconds(period) <= env.block.time,
+                None => true,
+            };
+            if record.verified.is_some() && expired {
+                record.verified = None;
+            }
+            record
+        })
+        .collect())
+}
+
+// The image NFT can be sold or burned after it was set
+pub fn query_image_nft_status(deps: Deps, name: &str) -> StdResult<ImageNftStatusResponse> {
+    let name = resolve_alias(deps.storage, name)?;
+    let token = Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, &name)?;
+
+    let owned = token
+        .extension
+        .image_nft
+        .as_ref()
+        .is_some_and(|nft| image_nft_owner(deps, nft).is_ok_and(|owner| owner == token.owner));
+
+    Ok(ImageNftStatusResponse {
+        nft: token.extension.image_nft,
+        owned,
+    })
+}
+
+pub fn query_text_records(
+    deps: Deps,
+    env: &Env,
+    name: &str,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    verified_only: bool,
+) -> StdResult<Vec<TextRecord>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    let name = resolve_alias(deps.storage, name)?;
+    // fails for unknown names
+    Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, &name)?;
+    // a name has few records, and some may not be migrated yet
+    let records = load_records(deps.storage, &name)?
+        .into_iter()
+        .filter(|record| start_after.as_deref() < Some(record.name.as_str()))
+        .collect();
+
+    Ok(expire_verifications(deps, env, records)?
+        .into_iter()
+        .filter(|r| !verified_only || r.verified == Some(true))
+        .take(limit)
+        .collect())
+}
+
+pub fn query_text_record(
+    deps: Deps,
+    env: &Env,
+    name: &str,
+    record_name: &str,
+) -> StdResult<Option<TextRecord>> {
+    let name = resolve_alias(deps.storage, name)?;
+    // fails for unknown names
+    Sg721NameContract::default()
+        .tokens
+        .load(deps.storage, &name)?;
+    let record = find_record(deps, &name, record_name)?;
+
+    Ok(expire_verifications(deps, env, record.into_iter().collect())?.pop())
+}
+
+// Records are not stored in the token extension, so they are added back to the metadata
+pub fn query_nft_info(
+    deps: Deps,
+    env: &Env,
+    token_id: String,
+) -> StdResult<NftInfoResponse<Metadata>> {
+    let mut res = Sg721NameContract::default()
+        .parent
+        .nft_info(deps, token_id.clone())?;
+    res.extension.records =
+        expire_verifications(deps, env, load_records(deps.storage, &token_id)?)?;
+    Ok(res)
+}
+
+pub fn query_all_nft_info(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    include_expired: bool,
+) -> StdResult<AllNftInfoResponse<Metadata>> {
+    let mut res = Sg721NameContract::default().parent.all_nft_info(
+        deps,
+        env.clone(),
+        token_id.clone(),
+        include_expired,
+    )?;
+    res.info.extension.records =
+        expire_verifications(deps, &env, load_records(deps.storage, &token_id)?)?;
+    Ok(res)
+}
+
+pub fn query_is_verified(deps: Deps, env: &Env, name: &str, record_name: &str) -> StdResult<bool> {
+    Ok(query_text_record(deps, env, name, record_name)?
+        .is_some_and(|record| record.verified == Some(true)))
+}
+
+// Aliases keep their own records, so verification claims are checked without following them
+fn is_verified(deps: Deps, env: &Env, token_id: &str, record_name: &str) -> StdResult<bool> {
+    let record = find_record(deps, token_id, record_name)?;
+    Ok(
+        expire_verifications(deps, env, record.into_iter().collect())?
+            .pop()
+            .is_some_and(|record| record.verified == Some(true)),
+    )
 }
 
 pub fn transcode(address: &str) -> StdResult<String> {
@@ -632,7 +2712,7 @@ fn validate_address(deps: Deps, sender: &Addr, addr: Addr) -> Result<Addr, Contr
         } else {
             // If there is no admin and the creator is not the sender, check creator's admin
             let creator_info = deps.querier.query_wasm_contract_info(&creator)?;
-            if creator_info.admin.map_or(true, |a| &a != sender) {
+            if creator_info.admin.as_deref() != Some(sender.as_str()) {
                 return Err(ContractError::UnauthorizedCreatorOrAdmin {});
             }
         }
@@ -38,6 +38,45 @@ pub enum ContractError {
     #[error("RecordValueEmpty")]
     RecordValueEmpty {},
 
+    #[error("InvalidUrl")]
+    InvalidUrl {},
+
+    #[error("InvalidAvatar")]
+    InvalidAvatar {},
+
+    #[error("InvalidEmail")]
+    InvalidEmail {},
+
+    #[error("InvalidHandle")]
+    InvalidHandle {},
+
+    #[error("InvalidPubKey")]
+    InvalidPubKey {},
+
+    #[error("InvalidContentHash")]
+    InvalidContentHash {},
+
+    #[error("AliasCycle")]
+    AliasCycle {},
+
+    #[error("TooManyAliasHops max: {max}")]
+    TooManyAliasHops { max: u32 },
+
+    #[error("FuseBurned")]
+    FuseBurned {},
+
+    #[error("InvalidFuses")]
+    InvalidFuses {},
+
+    #[error("NoAssociatedAddress")]
+    NoAssociatedAddress {},
+
+    #[error("InvalidPubKeyProof")]
+    InvalidPubKeyProof {},
+
+    #[error("PubKeyNotFound")]
+    PubKeyNotFound {},
+
     #[error("UnauthorizedVerification")]
     UnauthorizedVerification {},
 
@@ -47,9 +86,57 @@ pub enum ContractError {
     #[error("Unauthorized: Not contract creator or admin")]
     UnauthorizedCreatorOrAdmin {},
 
+    #[error("Chain addresses and public keys can not be set at mint")]
+    UnsupportedMintMetadata {},
+
     #[error("TooManyRecords max: {max}")]
     TooManyRecords { max: u32 },
 
+    #[error("InvalidVerifierScope")]
+    InvalidVerifierScope {},
+
+    #[error("VerifierNotFound")]
+    VerifierNotFound {},
+
+    #[error("ImageNftNotOwned")]
+    ImageNftNotOwned {},
+
+    #[error("RecordAlreadyVerified")]
+    RecordAlreadyVerified {},
+
+    #[error("RecordNotFound")]
+    RecordNotFound {},
+
+    #[error("UnknownAttestor")]
+    UnknownAttestor {},
+
+    #[error("RecordsMigrated")]
+    RecordsMigrated {},
+
+    #[error("InvalidAttestation")]
+    InvalidAttestation {},
+
+    #[error("AttestationExpired")]
+    AttestationExpired {},
+
+    #[error("InvalidChainAddress")]
+    InvalidChainAddress {},
+
+    #[error("InvalidChainAddressProof")]
+    InvalidChainAddressProof {},
+
+    #[error("ChainAddressAlreadyExists")]
+    ChainAddressAlreadyExists {},
+
+    #[error("InvalidSubname")]
+    InvalidSubname {},
+
+    #[error("NotASubname")]
+    NotASubname {},
+
+    #[error("TooManySubnames")]
+    TooManySubnames {},
+
     #[error("NotImplemented")]
     NotImplemented {},
 }
@@ -1,7 +1,7 @@
-use crate::msg::QueryMsg;
+use crate::msg::{AssociationResponse, QueryMsg};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, Addr, QuerierWrapper, QueryRequest, StdResult, WasmQuery};
-use sg_name::{TextRecord, NFT};
+use sg_name::{ContentHash, PubKey, TextRecord, NFT};
 
 /// NameCollectionContract is a wrapper around Addr that provides a lot of helpers
 #[cw_serde]
@@ -23,6 +23,55 @@ impl NameCollectionContract {
         Ok(res)
     }
 
+    pub fn names(
+        &self,
+        querier: &QuerierWrapper,
+        addresses: &[String],
+    ) -> StdResult<Vec<(String, Option<String>)>> {
+        let res: Vec<(String, Option<String>)> =
+            querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                contract_addr: self.addr().into(),
+                msg: to_json_binary(&QueryMsg::Names {
+                    addresses: addresses.to_vec(),
+                })?,
+            }))?;
+
+        Ok(res)
+    }
+
+    pub fn associated_addresses(
+        &self,
+        querier: &QuerierWrapper,
+        names: &[String],
+    ) -> StdResult<Vec<(String, Option<String>)>> {
+        let res: Vec<(String, Option<String>)> =
+            querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                contract_addr: self.addr().into(),
+                msg: to_json_binary(&QueryMsg::AssociatedAddresses {
+                    names: names.to_vec(),
+                })?,
+            }))?;
+
+        Ok(res)
+    }
+
+    pub fn address_for(
+        &self,
+        querier: &QuerierWrapper,
+        name: &str,
+        chain: &str,
+    ) -> StdResult<String> {
+        let res: String = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&QueryMsg::AddressFor {
+                name: name.to_string(),
+                chain: chain.to_string(),
+            })?,
+        }))?;
+
+        Ok(res)
+    }
+
     pub fn image_nft(&self, querier: &QuerierWrapper, name: &str) -> StdResult<Option<NFT>> {
         let res: Option<NFT> = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
             contract_addr: self.addr().into(),
@@ -34,22 +83,140 @@ impl NameCollectionContract {
         Ok(res)
     }
 
-    pub fn text_records(&self, querier: &QuerierWrapper, name: &str) -> StdResult<Vec<TextRecord>> {
+    pub fn content_hash(
+        &self,
+        querier: &QuerierWrapper,
+        name: &str,
+    ) -> StdResult<Option<ContentHash>> {
+        let res: Option<ContentHash> = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&QueryMsg::ContentHash {
+                name: name.to_string(),
+            })?,
+        }))?;
+
+        Ok(res)
+    }
+
+    pub fn alias_of(&self, querier: &QuerierWrapper, name: &str) -> StdResult<Option<String>> {
+        let res: Option<String> = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&QueryMsg::AliasOf {
+                name: name.to_string(),
+            })?,
+        }))?;
+
+        Ok(res)
+    }
+
+    pub fn association_history(
+        &self,
+        querier: &QuerierWrapper,
+        name: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<AssociationResponse>> {
+        let res: Vec<AssociationResponse> =
+            querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                contract_addr: self.addr().into(),
+                msg: to_json_binary(&QueryMsg::AssociationHistory {
+                    name: name.to_string(),
+                    start_after,
+                    limit,
+                })?,
+            }))?;
+
+        Ok(res)
+    }
+
+    pub fn name_at(
+        &self,
+        querier: &QuerierWrapper,
+        address: &str,
+        height: u64,
+    ) -> StdResult<Option<String>> {
+        let res: Option<String> = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&QueryMsg::NameAt {
+                address: address.to_string(),
+                height,
+            })?,
+        }))?;
+
+        Ok(res)
+    }
+
+    pub fn fuses(&self, querier: &QuerierWrapper, name: &str) -> StdResult<u32> {
+        let res: u32 = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&QueryMsg::Fuses {
+                name: name.to_string(),
+            })?,
+        }))?;
+
+        Ok(res)
+    }
+
+    pub fn pub_keys(&self, querier: &QuerierWrapper, name: &str) -> StdResult<Vec<PubKey>> {
+        let res: Vec<PubKey> = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&QueryMsg::PubKeys {
+                name: name.to_string(),
+            })?,
+        }))?;
+
+        Ok(res)
+    }
+
+    pub fn text_records(
+        &self,
+        querier: &QuerierWrapper,
+        name: &str,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        verified_only: bool,
+    ) -> StdResult<Vec<TextRecord>> {
         let res: Vec<TextRecord> = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
             contract_addr: self.addr().into(),
             msg: to_json_binary(&QueryMsg::TextRecords {
                 name: name.to_string(),
+                start_after,
+                limit,
+                verified_only: Some(verified_only),
+            })?,
+        }))?;
+
+        Ok(res)
+    }
+
+    pub fn text_record(
+        &self,
+        querier: &QuerierWrapper,
+        name: &str,
+        record_name: &str,
+    ) -> StdResult<Option<TextRecord>> {
+        let res: Option<TextRecord> = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&QueryMsg::TextRecord {
+                name: name.to_string(),
+                record_name: record_name.to_string(),
             })?,
         }))?;
 
         Ok(res)
     }
 
-    pub fn is_twitter_verified(&self, querier: &QuerierWrapper, name: &str) -> StdResult<bool> {
+    pub fn is_verified(
+        &self,
+        querier: &QuerierWrapper,
+        name: &str,
+        record_name: &str,
+    ) -> StdResult<bool> {
         let res: bool = querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
             contract_addr: self.addr().into(),
-            msg: to_json_binary(&QueryMsg::IsTwitterVerified {
+            msg: to_json_binary(&QueryMsg::IsVerified {
                 name: name.to_string(),
+                record_name: record_name.to_string(),
             })?,
         }))?;
 
@@ -27,11 +27,22 @@ pub type QueryMsg = crate::msg::QueryMsg;
 pub mod entry {
     use crate::{
         contract::{
-            execute_verify_text_record, query_image_nft, query_is_twitter_verified,
-            query_text_records,
+            execute_add_chain_address, execute_add_manager, execute_add_verifier,
+            execute_batch_update, execute_burn_fuses, execute_create_subname,
+            execute_migrate_records, execute_remove_chain_address, execute_remove_manager,
+            execute_remove_pub_key, execute_remove_verifier, execute_request_verification,
+            execute_revoke_subname, execute_set_alias, execute_set_primary_name,
+            execute_set_pub_key, execute_transfer_subname, execute_transfer_with_metadata,
+            execute_update_chain_address, execute_update_content_hash, execute_verify_text_record,
+            execute_verify_text_record_with_signature, migrate_records, query_address_for,
+            query_alias_of, query_all_nft_info, query_association_history, query_attestors,
+            query_content_hash, query_fuses, query_image_nft, query_image_nft_status,
+            query_is_verified, query_managers, query_name_at, query_name_by_record, query_names,
+            query_nft_info, query_primary_name, query_pub_keys, query_subnames, query_text_record,
+            query_text_records, query_verifiers, ALL_RECORDS, RECORDS_MIGRATION_BATCH,
         },
         msg::InstantiateMsg,
-        state::{SudoParams, SUDO_PARAMS, VERIFIER},
+        state::{SudoParams, RECORDS_MIGRATION, SUDO_PARAMS, VERIFIERS},
     };
 
     use super::*;
@@ -40,19 +51,20 @@ pub mod entry {
         execute_add_text_record, execute_associate_address, execute_burn, execute_mint,
         execute_remove_text_record, execute_send_nft, execute_set_name_marketplace,
         execute_transfer_nft, execute_update_image_nft, execute_update_text_record,
-        query_associated_address, query_name, query_name_marketplace, query_params,
+        query_associated_address, query_associated_addresses, query_name, query_name_marketplace,
+        query_params,
     };
     use cosmwasm_std::{
         to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, StdError, StdResult,
     };
     use cw2::set_contract_version;
-    use cw_utils::maybe_addr;
+    use cw_controllers::Admin;
     use sg721_base::ContractError as Sg721ContractError;
     use sg_std::Response;
 
     #[cfg_attr(not(feature = "library"), entry_point)]
     pub fn instantiate(
-        mut deps: DepsMut,
+        deps: DepsMut,
         env: Env,
         info: MessageInfo,
         msg: InstantiateMsg,
@@ -64,11 +76,16 @@ pub mod entry {
             deps.storage,
             &SudoParams {
                 max_record_count: 10,
+                verification_period: None,
+                max_history_count: None,
             },
         )?;
 
-        let api = deps.api;
-        VERIFIER.set(deps.branch(), maybe_addr(api, msg.verifier)?)?;
+        // The initial verifier can verify all records and add other verifiers
+        if let Some(verifier) = msg.verifier {
+            let verifier = deps.api.addr_validate(&verifier)?;
+            VERIFIERS.save(deps.storage, (ALL_RECORDS, &verifier), &Empty {})?;
+        }
 
         let res =
             Sg721NameContract::default().instantiate(deps, env.clone(), info, msg.base_init_msg)?;
@@ -85,35 +102,103 @@ pub mod entry {
         info: MessageInfo,
         msg: ExecuteMsg,
     ) -> Result<Response, ContractError> {
-        let api = deps.api;
-
         match msg {
             ExecuteMsg::AssociateAddress { name, address } => {
-                execute_associate_address(deps, info, name, address)
+                execute_associate_address(deps, env, info, name, address)
             }
             ExecuteMsg::UpdateImageNft { name, nft } => {
-                execute_update_image_nft(deps, info, name, nft)
+                execute_update_image_nft(deps, env, info, name, nft)
             }
+            ExecuteMsg::UpdateContentHash { name, content_hash } => {
+                execute_update_content_hash(deps, env, info, name, content_hash)
+            }
+            ExecuteMsg::SetPubKey {
+                name,
+                pub_key,
+                signer,
+                signature,
+            } => execute_set_pub_key(deps, env, info, name, pub_key, signer, signature),
+            ExecuteMsg::RemovePubKey { name, algo } => {
+                execute_remove_pub_key(deps, env, info, name, algo)
+            }
+            ExecuteMsg::SetAlias { name, target } => {
+                execute_set_alias(deps, env, info, name, target)
+            }
+            ExecuteMsg::BurnFuses { name, fuses } => execute_burn_fuses(deps, info, name, fuses),
             ExecuteMsg::AddTextRecord { name, record } => {
-                execute_add_text_record(deps, info, name, record)
+                execute_add_text_record(deps, env, info, name, record)
             }
             ExecuteMsg::RemoveTextRecord { name, record_name } => {
-                execute_remove_text_record(deps, info, name, record_name)
+                execute_remove_text_record(deps, env, info, name, record_name)
             }
             ExecuteMsg::UpdateTextRecord { name, record } => {
-                execute_update_text_record(deps, info, name, record)
+                execute_update_text_record(deps, env, info, name, record)
+            }
+            ExecuteMsg::AddChainAddress {
+                name,
+                address,
+                proof,
+            } => execute_add_chain_address(deps, env, info, name, address, proof),
+            ExecuteMsg::RemoveChainAddress { name, chain } => {
+                execute_remove_chain_address(deps, env, info, name, chain)
+            }
+            ExecuteMsg::UpdateChainAddress {
+                name,
+                address,
+                proof,
+            } => execute_update_chain_address(deps, env, info, name, address, proof),
+            ExecuteMsg::BatchUpdate { name, ops } => {
+                execute_batch_update(deps, env, info, name, ops)
             }
             ExecuteMsg::VerifyTextRecord {
                 name,
                 record_name,
                 result,
-            } => execute_verify_text_record(deps, info, name, record_name, result),
-            ExecuteMsg::UpdateVerifier { verifier } => {
-                Ok(VERIFIER.execute_update_admin(deps, info, maybe_addr(api, verifier)?)?)
+            } => execute_verify_text_record(deps, env, info, name, record_name, result),
+            ExecuteMsg::RequestVerification { name, record_name } => {
+                execute_request_verification(deps, env, info, name, record_name)
+            }
+            ExecuteMsg::VerifyTextRecordWithSignature {
+                name,
+                record,
+                issued_at,
+                pub_key,
+                signature,
+            } => execute_verify_text_record_with_signature(
+                deps, env, info, name, record, issued_at, pub_key, signature,
+            ),
+            ExecuteMsg::AddVerifier { verifier, scope } => {
+                execute_add_verifier(deps, info, verifier, scope)
+            }
+            ExecuteMsg::RemoveVerifier { verifier, scope } => {
+                execute_remove_verifier(deps, info, verifier, scope)
+            }
+            ExecuteMsg::TransferWithMetadata {
+                recipient,
+                token_id,
+            } => execute_transfer_with_metadata(deps, env, info, recipient, token_id),
+            ExecuteMsg::SetPrimaryName { name } => execute_set_primary_name(deps, info, name),
+            ExecuteMsg::AddManager {
+                name,
+                manager,
+                expires,
+            } => execute_add_manager(deps, info, name, manager, expires),
+            ExecuteMsg::RemoveManager { name, manager } => {
+                execute_remove_manager(deps, info, name, manager)
             }
+            ExecuteMsg::CreateSubname {
+                parent,
+                label,
+                owner,
+            } => execute_create_subname(deps, info, parent, label, owner),
+            ExecuteMsg::TransferSubname { name, recipient } => {
+                execute_transfer_subname(deps, env, info, name, recipient)
+            }
+            ExecuteMsg::RevokeSubname { name } => execute_revoke_subname(deps, env, info, name),
             ExecuteMsg::SetNameMarketplace { address } => {
                 execute_set_name_marketplace(deps, info, address)
             }
+            ExecuteMsg::MigrateRecords { limit } => execute_migrate_records(deps, info, limit),
             ExecuteMsg::TransferNft {
                 recipient,
                 token_id,
@@ -123,7 +208,7 @@ pub mod entry {
                 token_id,
                 msg,
             } => execute_send_nft(deps, env, info, contract, token_id, msg),
-            ExecuteMsg::Mint(msg) => execute_mint(deps, info, msg),
+            ExecuteMsg::Mint(msg) => execute_mint(deps, env, info, msg),
             ExecuteMsg::Burn { token_id } => execute_burn(deps, env, info, token_id),
             _ => Sg721NameContract::default()
                 .execute(deps, env, info, msg.into())
@@ -137,21 +222,93 @@ pub mod entry {
             QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
             QueryMsg::NameMarketplace {} => to_json_binary(&query_name_marketplace(deps)?),
             QueryMsg::Name { address } => to_json_binary(&query_name(deps, address)?),
-            QueryMsg::Verifier {} => to_json_binary(&VERIFIER.query_admin(deps)?),
+            QueryMsg::Names { addresses } => to_json_binary(&query_names(deps, addresses)?),
+            QueryMsg::NameByRecord {
+                record_name,
+                value,
+                verified_only,
+            } => to_json_binary(&query_name_by_record(
+                deps,
+                &env,
+                &record_name,
+                &value,
+                verified_only,
+            )?),
+            QueryMsg::Verifiers {} => to_json_binary(&query_verifiers(deps)?),
+            QueryMsg::Attestors { record_name } => {
+                to_json_binary(&query_attestors(deps, &record_name)?)
+            }
+            QueryMsg::Managers { name } => to_json_binary(&query_managers(deps, &name)?),
+            QueryMsg::Subnames {
+                parent,
+                start_after,
+                limit,
+            } => to_json_binary(&query_subnames(deps, &parent, start_after, limit)?),
             QueryMsg::AssociatedAddress { name } => {
                 to_json_binary(&query_associated_address(deps, &name)?)
             }
+            QueryMsg::AssociatedAddresses { names } => {
+                to_json_binary(&query_associated_addresses(deps, names)?)
+            }
+            QueryMsg::PrimaryName { address } => {
+                to_json_binary(&query_primary_name(deps, address)?)
+            }
+            QueryMsg::AddressFor { name, chain } => {
+                to_json_binary(&query_address_for(deps, &name, &chain)?)
+            }
             QueryMsg::ImageNFT { name } => to_json_binary(&query_image_nft(deps, &name)?),
-            QueryMsg::TextRecords { name } => to_json_binary(&query_text_records(deps, &name)?),
-            QueryMsg::IsTwitterVerified { name } => {
-                to_json_binary(&query_is_twitter_verified(deps, &name)?)
+            QueryMsg::ContentHash { name } => to_json_binary(&query_content_hash(deps, &name)?),
+            QueryMsg::AssociationHistory {
+                name,
+                start_after,
+                limit,
+            } => to_json_binary(&query_association_history(deps, &name, start_after, limit)?),
+            QueryMsg::NameAt { address, height } => {
+                to_json_binary(&query_name_at(deps, address, height)?)
             }
+            QueryMsg::AliasOf { name } => to_json_binary(&query_alias_of(deps, &name)?),
+            QueryMsg::Fuses { name } => to_json_binary(&query_fuses(deps, &name)?),
+            QueryMsg::PubKeys { name } => to_json_binary(&query_pub_keys(deps, &name)?),
+            QueryMsg::ImageNFTStatus { name } => {
+                to_json_binary(&query_image_nft_status(deps, &name)?)
+            }
+            QueryMsg::TextRecords {
+                name,
+                start_after,
+                limit,
+                verified_only,
+            } => to_json_binary(&query_text_records(
+                deps,
+                &env,
+                &name,
+                start_after,
+                limit,
+                verified_only.unwrap_or(false),
+            )?),
+            QueryMsg::TextRecord { name, record_name } => {
+                to_json_binary(&query_text_record(deps, &env, &name, &record_name)?)
+            }
+            QueryMsg::IsVerified { name, record_name } => {
+                to_json_binary(&query_is_verified(deps, &env, &name, &record_name)?)
+            }
+            QueryMsg::NftInfo { token_id } => {
+                to_json_binary(&query_nft_info(deps, &env, token_id)?)
+            }
+            QueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+            } => to_json_binary(&query_all_nft_info(
+                deps,
+                env,
+                token_id,
+                include_expired.unwrap_or(false),
+            )?),
             _ => Sg721NameContract::default().query(deps, env, msg.into()),
         }
     }
 
     #[cfg_attr(not(feature = "library"), entry_point)]
-    pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, ContractError> {
+    pub fn migrate(mut deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, ContractError> {
         let current_version = cw2::get_contract_version(deps.storage)?;
         if current_version.contract != CONTRACT_NAME {
             return Err(StdError::generic_err("Cannot upgrade to a different contract").into());
@@ -174,8 +331,20 @@ pub mod entry {
             return Ok(Response::new());
         }
 
+        // move the single verification oracle into the scoped registry
+        let legacy_verifier = Admin::new("verifier");
+        if let Some(verifier) = legacy_verifier.get(deps.as_ref())? {
+            VERIFIERS.save(deps.storage, (ALL_RECORDS, &verifier), &Empty {})?;
+            legacy_verifier.set(deps.branch(), None)?;
+        }
+
+        // move text records out of the token extensions into their own map,
+        // tokens left after the first batch are moved with `MigrateRecords`
+        RECORDS_MIGRATION.save(deps.storage, &None)?;
+        let done = migrate_records(deps.storage, RECORDS_MIGRATION_BATCH)?;
+
         // set new contract version
         set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-        Ok(Response::new())
+        Ok(Response::new().add_attribute("records_migrated", done.to_string()))
     }
 }
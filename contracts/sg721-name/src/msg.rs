@@ -10,9 +10,12 @@ use sg721::{
     UpdateCollectionInfoMsg,
 };
 use sg721_base::msg::{CollectionInfoResponse, QueryMsg as Sg721QueryMsg};
-use sg_name::{Metadata, TextRecord, NFT};
+use sg_name::{
+    ChainAddress, ChainAddressProof, ContentHash, KeyAlgo, Metadata, PubKey, RecordOp, TextRecord,
+    NFT,
+};
 
-use crate::state::SudoParams;
+use crate::state::{Attestor, SudoParams};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -26,6 +29,9 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg<T> {
     /// Set name marketplace contract address
     SetNameMarketplace { address: String },
+    /// Move the text records of the next `limit` names out of the token extensions,
+    /// callable by anyone until the records migration is done
+    MigrateRecords { limit: Option<u32> },
     /// Set an address for name reverse lookup and updates token_uri
     /// Can be an EOA or a contract address.
     AssociateAddress {
@@ -34,20 +40,108 @@ pub enum ExecuteMsg<T> {
     },
     /// Update image NFT
     UpdateImageNft { name: String, nft: Option<NFT> },
+    /// Set the content hash of a name's website, or clear it with `None`
+    UpdateContentHash {
+        name: String,
+        content_hash: Option<ContentHash>,
+    },
+    /// Publish an encryption key on a name, replacing the key with the same algorithm.
+    /// `signer` is the secp256k1 public key of the associated address, which signs
+    /// the sha256 hash of `PubKeyAttestation` encoded as JSON.
+    SetPubKey {
+        name: String,
+        pub_key: PubKey,
+        signer: Binary,
+        signature: Binary,
+    },
+    /// Remove the encryption key for an algorithm
+    RemovePubKey { name: String, algo: KeyAlgo },
+    /// Make a name resolve to the address, records and image of another name,
+    /// or stop forwarding with `None`
+    SetAlias {
+        name: String,
+        target: Option<String>,
+    },
+    /// Permanently forbid changes to a name, only callable by the owner.
+    /// `fuses` is a combination of the `CANNOT_*` bits in `state`.
+    /// A subname with burned fuses can no longer be revoked by its parent.
+    BurnFuses { name: String, fuses: u32 },
     /// Add text record ex: twitter handle, discord name, etc
     AddTextRecord { name: String, record: TextRecord },
     /// Remove text record ex: twitter handle, discord name, etc
     RemoveTextRecord { name: String, record_name: String },
     /// Update text record ex: twitter handle, discord name, etc
     UpdateTextRecord { name: String, record: TextRecord },
+    /// Apply several metadata updates at once, `max_record_count` is checked after all of them
+    BatchUpdate { name: String, ops: Vec<RecordOp> },
     /// Verify a text record as true or false (via oracle)
     VerifyTextRecord {
         name: String,
         record_name: String,
         result: bool,
     },
-    /// Update the reset the verification oracle
-    UpdateVerifier { verifier: Option<String> },
+    /// Emit an event asking oracles to (re-)verify a text record
+    RequestVerification { name: String, record_name: String },
+    /// Verify a text record with a signature from a registered attestor.
+    /// The attestor signs `TextRecordAttestation` encoded as JSON, ed25519 keys
+    /// sign the bytes directly and secp256k1 keys sign their sha256 hash.
+    /// Signatures older than the verification period are rejected.
+    VerifyTextRecordWithSignature {
+        name: String,
+        record: TextRecord,
+        issued_at: Timestamp,
+        pub_key: Binary,
+        signature: Binary,
+    },
+    /// Allow an oracle to verify records in a scope, only callable by a verifier of all records.
+    /// A scope is a record name (`twitter`), a prefix (`social-*`) or `*` for all records.
+    AddVerifier { verifier: String, scope: String },
+    /// Remove an oracle from a scope, only callable by a verifier of all records
+    RemoveVerifier { verifier: String, scope: String },
+    /// Transfer a name keeping its records and image.
+    /// Verifications, managers, chain addresses and the associated address are cleared.
+    TransferWithMetadata { recipient: String, token_id: String },
+    /// Set one of the sender's names as its primary name, or clear it with `None`
+    SetPrimaryName { name: Option<String> },
+    /// Add an address for another chain ex: osmo, juno, eth
+    /// Stars addresses are set with `AssociateAddress`.
+    /// Addresses with the same key as the owner or the associated address are
+    /// accepted as is, others need a `proof` signed with the key of the address.
+    AddChainAddress {
+        name: String,
+        address: ChainAddress,
+        proof: Option<ChainAddressProof>,
+    },
+    /// Remove the address for a chain
+    RemoveChainAddress { name: String, chain: String },
+    /// Update the address for a chain, `proof` is needed as for `AddChainAddress`
+    UpdateChainAddress {
+        name: String,
+        address: ChainAddress,
+        proof: Option<ChainAddressProof>,
+    },
+    /// Allow an address to update records, image and associated address
+    /// of a name on behalf of the owner. Managers are removed on transfer.
+    AddManager {
+        name: String,
+        manager: String,
+        expires: Option<Expiration>,
+    },
+    /// Remove a manager of a name
+    RemoveManager { name: String, manager: String },
+    /// Create a subname (i.e: `pay.alice`) under a name owned by the sender.
+    /// A name can have up to `MAX_SUBNAMES` subnames.
+    CreateSubname {
+        parent: String,
+        label: String,
+        owner: String,
+    },
+    /// Move a subname to a new owner, only callable by the parent name owner
+    TransferSubname { name: String, recipient: String },
+    /// Burn a subname, only callable by the parent name owner.
+    /// A subname with burned fuses can't be revoked, and is kept when the parent
+    /// is sold or burned.
+    RevokeSubname { name: String },
     /// Transfer is a base message to move a token to another account without triggering actions
     TransferNft { recipient: String, token_id: String },
     /// Send is a base message to transfer a token to a contract and trigger an action
@@ -147,9 +241,86 @@ impl<T, E> From<ExecuteMsg<T>> for Sg721ExecuteMsg<T, E> {
     }
 }
 
+/// Message signed by an attestor to verify a text record
+#[cw_serde]
+pub struct TextRecordAttestation {
+    pub collection: String,
+    pub name: String,
+    pub owner: String,
+    pub record_name: String,
+    pub value: String,
+    /// The record is verified from this time
+    pub issued_at: Timestamp,
+}
+
+/// Message signed by the associated address to prove it controls an encryption key
+#[cw_serde]
+pub struct PubKeyAttestation {
+    pub collection: String,
+    pub name: String,
+    pub address: String,
+    pub pub_key: PubKey,
+}
+
+/// Message signed by the key of a chain address to prove it is controlled by the owner.
+/// EVM addresses are signed lowercase, as they are stored.
+#[cw_serde]
+pub struct ChainAddressAttestation {
+    pub collection: String,
+    pub name: String,
+    pub owner: String,
+    pub chain: String,
+    pub address: String,
+}
+
+#[cw_serde]
+pub struct AssociationResponse {
+    pub height: u64,
+    /// `None` when the association was removed
+    pub address: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct ImageNftStatusResponse {
+    pub nft: Option<NFT>,
+    pub owned: bool,
+}
+
+#[cw_serde]
+pub struct VerifierResponse {
+    pub verifier: Addr,
+    pub scope: String,
+}
+
+#[cw_serde]
+pub struct ManagerResponse {
+    pub manager: Addr,
+    pub expires: Expiration,
+}
+
 #[cw_serde]
 pub enum SudoMsg {
-    UpdateParams { max_record_count: u32 },
+    UpdateParams {
+        max_record_count: u32,
+    },
+    /// Association changes kept per name and per address,
+    /// `None` for `DEFAULT_MAX_HISTORY_COUNT`
+    UpdateMaxHistoryCount {
+        count: Option<u32>,
+    },
+    /// Seconds after which verifications expire, `None` for verifications that never expire
+    UpdateVerificationPeriod {
+        period: Option<u64>,
+    },
+    /// Register a key that can sign verifications for a record name
+    AddAttestor {
+        record_name: String,
+        attestor: Attestor,
+    },
+    RemoveAttestor {
+        record_name: String,
+        pub_key: Binary,
+    },
 }
 
 #[cw_serde]
@@ -161,24 +332,90 @@ pub enum QueryMsg {
     /// Reverse lookup of name for address
     #[returns(String)]
     Name { address: String },
+    /// Reverse lookup of names for many addresses, `None` for unmapped or invalid addresses
+    #[returns(Vec<(String, Option<String>)>)]
+    Names { addresses: Vec<String> },
     /// Returns the marketplace contract address
     #[returns(Addr)]
     NameMarketplace {},
     /// Returns the associated address for a name
     #[returns(Addr)]
     AssociatedAddress { name: String },
+    /// Returns the associated addresses for many names, `None` when not set or not resolvable
+    #[returns(Vec<(String, Option<String>)>)]
+    AssociatedAddresses { names: Vec<String> },
+    /// Returns the primary name of an address, falling back to its associated name
+    #[returns(Option<String>)]
+    PrimaryName { address: String },
+    /// Returns the address of a name for a chain ex: osmo, juno, eth
+    #[returns(String)]
+    AddressFor { name: String, chain: String },
     /// Returns the image NFT for a name
     #[returns(Option<NFT>)]
     ImageNFT { name: String },
-    /// Returns the text records for a name
+    /// Returns the content hash of the website of a name
+    #[returns(Option<ContentHash>)]
+    ContentHash { name: String },
+    /// Returns the addresses a name was associated with, oldest first
+    #[returns(Vec<AssociationResponse>)]
+    AssociationHistory {
+        name: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns the name an address was associated with at a block height
+    #[returns(Option<String>)]
+    NameAt { address: String, height: u64 },
+    /// Returns the name an alias forwards to
+    #[returns(Option<String>)]
+    AliasOf { name: String },
+    /// Returns the fuses burned on a name
+    #[returns(u32)]
+    Fuses { name: String },
+    /// Returns the encryption keys published on a name
+    #[returns(Vec<PubKey>)]
+    PubKeys { name: String },
+    /// Returns the image NFT for a name and if it is still owned by the name owner
+    #[returns(ImageNftStatusResponse)]
+    ImageNFTStatus { name: String },
+    /// Returns the text records for a name, ordered by record name
     #[returns(Vec<TextRecord>)]
-    TextRecords { name: String },
-    /// Returns if Twitter is verified for a name
+    TextRecords {
+        name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        verified_only: Option<bool>,
+    },
+    /// Returns a single text record of a name
+    #[returns(Option<TextRecord>)]
+    TextRecord { name: String, record_name: String },
+    /// Returns if a text record of a name is verified
     #[returns(bool)]
-    IsTwitterVerified { name: String },
-    /// Returns the verification oracle address
-    #[returns(Option<String>)]
-    Verifier {},
+    IsVerified { name: String, record_name: String },
+    /// Returns the name that claims a text record value, preferring a verified claim.
+    /// Handles (twitter, github, discord, telegram) are matched case insensitively.
+    #[returns(String)]
+    NameByRecord {
+        record_name: String,
+        value: String,
+        verified_only: bool,
+    },
+    /// Returns the verification oracles and their scopes
+    #[returns(Vec<VerifierResponse>)]
+    Verifiers {},
+    /// Returns the attestors that can verify a record
+    #[returns(Vec<Attestor>)]
+    Attestors { record_name: String },
+    /// Returns the managers of a name
+    #[returns(Vec<ManagerResponse>)]
+    Managers { name: String },
+    /// Returns the subnames (i.e: `pay.alice`) of a name
+    #[returns(Vec<String>)]
+    Subnames {
+        parent: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// Everything below is inherited from sg721
     #[returns(OwnerOfResponse)]
     OwnerOf {
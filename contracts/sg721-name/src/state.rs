@@ -1,13 +1,20 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_controllers::Admin;
+use cosmwasm_std::{Addr, Binary, Empty};
 use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
+use sg_name::TextRecord;
 
 #[cw_serde]
 pub struct SudoParams {
     pub max_record_count: u32,
+    /// Seconds a text record verification stays valid, forever if not set
+    pub verification_period: Option<u64>,
+    /// Association changes kept per name and per address, `DEFAULT_MAX_HISTORY_COUNT` if not set
+    pub max_history_count: Option<u32>,
 }
 
+pub const DEFAULT_MAX_HISTORY_COUNT: u32 = 100;
+
 pub const SUDO_PARAMS: Item<SudoParams> = Item::new("params");
 pub const NAME_MARKETPLACE: Item<Addr> = Item::new("name-marketplace");
 
@@ -17,5 +24,74 @@ pub type TokenId = String;
 /// Address (bech32) -> name
 pub const REVERSE_MAP: Map<&TokenUri, TokenId> = Map::new("rm");
 
-/// Address of the text record verification oracle
-pub const VERIFIER: Admin = Admin::new("verifier");
+/// Owner -> name chosen as primary among the names it owns
+pub const PRIMARY_NAME: Map<&Addr, TokenId> = Map::new("pn");
+
+/// (chain, address) -> name
+/// Reverse lookup for addresses set with `AddChainAddress`
+pub const CHAIN_REVERSE_MAP: Map<(&str, &str), TokenId> = Map::new("crm");
+
+/// (name, record name) -> text record
+/// Records are kept out of the token extension, `NftInfo` still returns them in the metadata
+pub const RECORDS: Map<(&str, &str), TextRecord> = Map::new("r");
+
+/// Name after which text records are still to be moved out of the token extensions.
+/// Only set while that migration is in progress, `None` until the first batch ran.
+pub const RECORDS_MIGRATION: Item<Option<TokenId>> = Item::new("rmg");
+
+/// (name, block height) -> associated address, `None` when the association was removed
+pub const NAME_HISTORY: Map<(&str, u64), Option<Addr>> = Map::new("nh");
+
+/// (address, block height) -> name associated with the address, `None` when it was removed
+pub const ADDRESS_HISTORY: Map<(&Addr, u64), Option<TokenId>> = Map::new("ah");
+
+/// (record name, record value, name) -> verified
+/// Reverse lookup of the names claiming a text record value
+pub const RECORD_INDEX: Map<(&str, &str, &str), bool> = Map::new("ri");
+
+/// (record name, record value) -> name
+/// The only name that can have a record value verified
+pub const VERIFIED_RECORDS: Map<(&str, &str), TokenId> = Map::new("vr");
+
+/// (scope, verifier) -> Empty
+/// A scope is a record name (`twitter`), a record name prefix (`social-*`) or `*` for all records
+pub const VERIFIERS: Map<(&str, &Addr), Empty> = Map::new("vs");
+
+#[cw_serde]
+pub enum KeyType {
+    Secp256k1,
+    Ed25519,
+}
+
+#[cw_serde]
+pub struct Attestor {
+    pub key_type: KeyType,
+    pub pub_key: Binary,
+}
+
+/// (record name, attestor public key) -> key type
+/// Attestors sign off-chain proofs that verify text records, managed by sudo
+pub const ATTESTORS: Map<(&str, &[u8]), KeyType> = Map::new("at");
+
+/// (name, manager) -> expiration
+/// Managers can update records of a name, but can not transfer or burn it
+pub const MANAGERS: Map<(&str, &Addr), Expiration> = Map::new("m");
+
+/// Alias -> target name
+/// Resolution queries on an alias follow to the target, the alias keeps its own ownership
+pub const ALIASES: Map<&str, TokenId> = Map::new("al");
+
+/// Name -> fuses burned by its owner, kept across transfers
+pub const FUSES: Map<&str, u32> = Map::new("fu");
+
+/// Fuses permanently forbid changes to a name once burned
+pub const CANNOT_SET_ADDRESS: u32 = 1;
+pub const CANNOT_SET_RECORDS: u32 = 1 << 1;
+pub const CANNOT_TRANSFER: u32 = 1 << 2;
+pub const CANNOT_CREATE_SUBNAMES: u32 = 1 << 3;
+pub const ALL_FUSES: u32 =
+    CANNOT_SET_ADDRESS | CANNOT_SET_RECORDS | CANNOT_TRANSFER | CANNOT_CREATE_SUBNAMES;
+
+/// (parent name, subname) -> Empty
+/// Subnames are tokens in this collection with a `<label>.<parent>` token id
+pub const SUBNAMES: Map<(&str, &str), Empty> = Map::new("sn");
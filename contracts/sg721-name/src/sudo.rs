@@ -1,24 +1,99 @@
 use crate::{
     msg::SudoMsg,
-    state::{SudoParams, SUDO_PARAMS},
+    state::{Attestor, ATTESTORS, SUDO_PARAMS},
     ContractError,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Env, Event};
+use cosmwasm_std::{Binary, DepsMut, Env, Event, StdResult};
 use sg_std::Response;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
     match msg {
         SudoMsg::UpdateParams { max_record_count } => sudo_update_params(deps, max_record_count),
+        SudoMsg::UpdateMaxHistoryCount { count } => sudo_update_max_history_count(deps, count),
+        SudoMsg::UpdateVerificationPeriod { period } => {
+            sudo_update_verification_period(deps, period)
+        }
+        SudoMsg::AddAttestor {
+            record_name,
+            attestor,
+        } => sudo_add_attestor(deps, record_name, attestor),
+        SudoMsg::RemoveAttestor {
+            record_name,
+            pub_key,
+        } => sudo_remove_attestor(deps, record_name, pub_key),
     }
 }
 
 pub fn sudo_update_params(deps: DepsMut, max_record_count: u32) -> Result<Response, ContractError> {
-    SUDO_PARAMS.save(deps.storage, &SudoParams { max_record_count })?;
+    SUDO_PARAMS.update(deps.storage, |mut params| -> StdResult<_> {
+        params.max_record_count = max_record_count;
+        Ok(params)
+    })?;
 
     let event =
         Event::new("update-params").add_attribute("max_record_count", max_record_count.to_string());
     Ok(Response::new().add_event(event))
 }
+
+pub fn sudo_update_max_history_count(
+    deps: DepsMut,
+    count: Option<u32>,
+) -> Result<Response, ContractError> {
+    SUDO_PARAMS.update(deps.storage, |mut params| -> StdResult<_> {
+        params.max_history_count = count;
+        Ok(params)
+    })?;
+
+    let event = Event::new("update-max-history-count")
+        .add_attribute("count", count.map_or("none".to_string(), |c| c.to_string()));
+    Ok(Response::new().add_event(event))
+}
+
+pub fn sudo_update_verification_period(
+    deps: DepsMut,
+    period: Option<u64>,
+) -> Result<Response, ContractError> {
+    SUDO_PARAMS.update(deps.storage, |mut params| -> StdResult<_> {
+        params.verification_period = period;
+        Ok(params)
+    })?;
+
+    let event = Event::new("update-verification-period").add_attribute(
+        "period",
+        period.map_or("none".to_string(), |p| p.to_string()),
+    );
+    Ok(Response::new().add_event(event))
+}
+
+pub fn sudo_add_attestor(
+    deps: DepsMut,
+    record_name: String,
+    attestor: Attestor,
+) -> Result<Response, ContractError> {
+    ATTESTORS.save(
+        deps.storage,
+        (&record_name, attestor.pub_key.as_slice()),
+        &attestor.key_type,
+    )?;
+
+    let event = Event::new("add-attestor")
+        .add_attribute("record_name", record_name)
+        .add_attribute("pub_key", attestor.pub_key.to_base64());
+    Ok(Response::new().add_event(event))
+}
+
+pub fn sudo_remove_attestor(
+    deps: DepsMut,
+    record_name: String,
+    pub_key: Binary,
+) -> Result<Response, ContractError> {
+    ATTESTORS.remove(deps.storage, (&record_name, pub_key.as_slice()));
+
+    let event = Event::new("remove-attestor")
+        .add_attribute("record_name", record_name)
+        .add_attribute("pub_key", pub_key.to_base64());
+    Ok(Response::new().add_event(event))
+}
@@ -1,20 +1,39 @@
 use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, ContractInfoResponse, ContractResult, Empty, OwnedDeps,
-    Querier, QuerierResult, QueryRequest, StdError, SystemError, SystemResult, WasmQuery,
+    from_json, to_json_binary, to_json_vec, Addr, Binary, ContractInfoResponse, ContractResult,
+    Deps, Empty, OwnedDeps, Querier, QuerierResult, QueryRequest, StdError, SystemError,
+    SystemResult, Timestamp, WasmQuery,
 };
-use cw721::Cw721Query;
+use cw721::{Cw721Query, Cw721QueryMsg, Expiration, NftInfoResponse, OwnerOfResponse};
 use cw721_base::MintMsg;
+use cw_controllers::Admin;
+use ripemd::Ripemd160;
 use sg721::{CollectionInfo, ExecuteMsg as Sg721ExecuteMsg, InstantiateMsg as Sg721InstantiateMsg};
-use sg721_base::ContractError::Unauthorized;
-use sg_name::{Metadata, TextRecord, NFT};
+use sg721_base::ContractError::{Claimed, Unauthorized};
+use sg_name::{
+    ChainAddress, ChainAddressProof, ContentCodec, ContentHash, KeyAlgo, Metadata, PubKey,
+    RecordOp, TextRecord, NFT,
+};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 use std::marker::PhantomData;
+use subtle_encoding::bech32;
 
-use crate::contract::{query_is_twitter_verified, query_name, query_text_records, transcode};
-use crate::entry::{execute, instantiate, query};
-use crate::msg::InstantiateMsg;
-use crate::state::SudoParams;
-use crate::{ContractError, ExecuteMsg, QueryMsg};
+use crate::contract::{
+    query_address_for, query_alias_of, query_associated_address, query_associated_addresses,
+    query_association_history, query_attestors, query_content_hash, query_image_nft_status,
+    query_is_verified, query_managers, query_name, query_name_at, query_name_by_record,
+    query_pub_keys, query_text_record, query_text_records, query_verifiers, transcode, ALL_RECORDS,
+    MAX_SUBNAMES, RECORDS_MIGRATION_BATCH,
+};
+use crate::entry::{execute, instantiate, migrate, query};
+use crate::msg::{
+    AssociationResponse, ChainAddressAttestation, InstantiateMsg, PubKeyAttestation, SudoMsg,
+    TextRecordAttestation, VerifierResponse,
+};
+use crate::state::{Attestor, KeyType, SudoParams, ALIASES, VERIFIERS};
+use crate::sudo::sudo;
+use crate::{contract, ContractError, ExecuteMsg, QueryMsg};
 pub type Sg721NameContract<'a> = sg721_base::Sg721Contract<'a, Metadata>;
 const CREATOR: &str = "creator";
 const IMPOSTER: &str = "imposter";
@@ -57,6 +76,20 @@ impl CustomMockQuerier {
                 response.creator = CREATOR.to_string();
                 SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
             }
+            // image NFTs are owned by the creator
+            QueryRequest::Wasm(WasmQuery::Smart {
+                contract_addr: _,
+                msg,
+            }) => match from_json(msg) {
+                Ok(Cw721QueryMsg::OwnerOf { .. }) => {
+                    let response = OwnerOfResponse {
+                        owner: CREATOR.to_string(),
+                        approvals: vec![],
+                    };
+                    SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+                }
+                _ => self.base.handle_query(request),
+            },
             _ => self.base.handle_query(request),
         }
     }
@@ -88,6 +121,43 @@ fn init_msg() -> InstantiateMsg {
     }
 }
 
+// instantiates the collection and mints `name` to `owner`
+fn setup_with_name(
+    deps: &mut OwnedDeps<MockStorage, MockApi, CustomMockQuerier, Empty>,
+    name: &str,
+    owner: &str,
+) {
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CREATOR, &[]),
+        init_msg(),
+    )
+    .unwrap();
+    mint_name(deps, name, owner);
+}
+
+fn mint_name(
+    deps: &mut OwnedDeps<MockStorage, MockApi, CustomMockQuerier, Empty>,
+    name: &str,
+    owner: &str,
+) {
+    let mint_msg = MintMsg::<Metadata> {
+        token_id: name.to_string(),
+        owner: owner.to_string(),
+        token_uri: None,
+        extension: Metadata::default(),
+    };
+    Sg721NameContract::default()
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR, &[]),
+            Sg721ExecuteMsg::Mint(mint_msg),
+        )
+        .unwrap();
+}
+
 #[test]
 fn init() {
     // instantiate sg-names collection
@@ -97,6 +167,161 @@ fn init() {
     instantiate(deps.as_mut(), mock_env(), info, init_msg()).unwrap();
 }
 
+#[test]
+fn migrate_verifier() {
+    let mut deps = mock_deps();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CREATOR, &[]),
+        init_msg(),
+    )
+    .unwrap();
+
+    // a collection from before verifier scopes, with a single verifier
+    cw2::set_contract_version(deps.as_mut().storage, "crates.io:sg721-name", "2.3.0").unwrap();
+    Admin::new("verifier")
+        .set(deps.as_mut(), Some(Addr::unchecked("oracle")))
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+
+    assert_eq!(
+        query_verifiers(deps.as_ref()).unwrap(),
+        vec![VerifierResponse {
+            verifier: Addr::unchecked("oracle"),
+            scope: ALL_RECORDS.to_string(),
+        }]
+    );
+    assert_eq!(Admin::new("verifier").get(deps.as_ref()).unwrap(), None);
+    let version = cw2::get_contract_version(deps.as_ref().storage).unwrap();
+    assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn migrate_records() {
+    let mut deps = mock_deps();
+    setup_with_name(&mut deps, "name000", CREATOR);
+    for i in 1..=RECORDS_MIGRATION_BATCH {
+        mint_name(&mut deps, &format!("name{:03}", i), CREATOR);
+    }
+
+    // a collection from before records were stored apart, with records in the extensions
+    cw2::set_contract_version(deps.as_mut().storage, "crates.io:sg721-name", "2.3.0").unwrap();
+    Admin::new("verifier").set(deps.as_mut(), None).unwrap();
+    let contract = Sg721NameContract::default();
+    let tokens = &contract.tokens;
+    let name = |i: u32| format!("name{:03}", i);
+    let last = name(RECORDS_MIGRATION_BATCH);
+    let removed = name(RECORDS_MIGRATION_BATCH - 1);
+    let transferred = name(RECORDS_MIGRATION_BATCH - 2);
+    let full = name(RECORDS_MIGRATION_BATCH - 3);
+    // handles used to keep the case they were set with
+    let legacy = |token_id: &str| {
+        let mut records = vec![TextRecord::new("twitter", token_id.to_uppercase())];
+        if token_id == transferred {
+            records[0].verified = Some(true);
+            records[0].verified_at = Some(mock_env().block.time);
+        }
+        if token_id == full {
+            records.extend((1..10).map(|i| TextRecord::new(format!("record{}", i), "value")));
+        }
+        records
+    };
+    for token_id in ["name000", &last, &removed, &transferred, &full] {
+        let mut token = tokens.load(&deps.storage, token_id).unwrap();
+        token.extension.records = legacy(token_id);
+        tokens.save(&mut deps.storage, token_id, &token).unwrap();
+    }
+    let migrated = |token_id: &str| {
+        let mut records = legacy(token_id);
+        records[0].value = token_id.to_string();
+        records
+    };
+
+    // the first batch is moved by the migration
+    migrate(deps.as_mut(), mock_env(), Empty {}).unwrap();
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), "name000", None, None, false).unwrap();
+    assert_eq!(records, vec![TextRecord::new("twitter", "name000")]);
+    let token = tokens.load(&deps.storage, "name000").unwrap();
+    assert!(token.extension.records.is_empty());
+
+    // tokens not migrated yet read their records from the extension
+    let records = query_text_records(deps.as_ref(), &mock_env(), &last, None, None, false).unwrap();
+    assert_eq!(records, migrated(&last));
+    let res: NftInfoResponse<Metadata> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::NftInfo {
+                token_id: last.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.extension.records, migrated(&last));
+
+    // and changes to them see the legacy records
+    let msg = ExecuteMsg::RemoveTextRecord {
+        name: removed.clone(),
+        record_name: "twitter".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &[]), msg).unwrap();
+    let msg = ExecuteMsg::AddTextRecord {
+        name: full.clone(),
+        record: TextRecord::new("discord", "full#0001"),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &[]), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::TooManyRecords { max: 10 }.to_string()
+    );
+    let msg = ExecuteMsg::SetNameMarketplace {
+        address: "marketplace".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &[]), msg).unwrap();
+    let msg = ExecuteMsg::TransferWithMetadata {
+        recipient: IMPOSTER.to_string(),
+        token_id: transferred.clone(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &[]), msg).unwrap();
+
+    // anyone can move the next ones
+    let msg = ExecuteMsg::MigrateRecords { limit: None };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IMPOSTER, &[]),
+        msg.clone(),
+    )
+    .unwrap();
+    let records = query_text_records(deps.as_ref(), &mock_env(), &last, None, None, false).unwrap();
+    assert_eq!(records, migrated(&last));
+    let res = query_name_by_record(
+        deps.as_ref(),
+        &mock_env(),
+        "twitter",
+        &last.to_uppercase(),
+        false,
+    )
+    .unwrap();
+    assert_eq!(res, last);
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), &removed, None, None, false).unwrap();
+    assert!(records.is_empty());
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), &transferred, None, None, false).unwrap();
+    assert_eq!(records, vec![TextRecord::new("twitter", &transferred)]);
+
+    let err = execute(deps.as_mut(), mock_env(), mock_info(IMPOSTER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::RecordsMigrated {}.to_string()
+    );
+}
+
 #[test]
 fn mint_and_update() {
     let contract = Sg721NameContract::default();
@@ -152,6 +377,7 @@ fn mint_and_update() {
         name: "test".to_string(),
         value: "test".to_string(),
         verified: None,
+        verified_at: None,
     };
     let update_record_msg = ExecuteMsg::UpdateTextRecord {
         name: token_id.to_string(),
@@ -162,12 +388,14 @@ fn mint_and_update() {
     let record: TextRecord = from_json(record_value).unwrap();
     assert_eq!(record, new_record);
 
-    let records = query_text_records(deps.as_ref(), token_id).unwrap();
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), token_id, None, None, false).unwrap();
     assert_eq!(records.len(), 1);
     assert_eq!(records[0].name, "test");
     assert_eq!(records[0].value, "test");
 
-    let is_twitter_verified = query_is_twitter_verified(deps.as_ref(), token_id).unwrap();
+    let is_twitter_verified =
+        query_is_verified(deps.as_ref(), &mock_env(), token_id, "twitter").unwrap();
     assert!(!is_twitter_verified);
 
     // trigger too many records error
@@ -176,6 +404,7 @@ fn mint_and_update() {
             name: format!("key{:?}", i),
             value: "value".to_string(),
             verified: None,
+            verified_at: None,
         };
         let update_record_msg = ExecuteMsg::UpdateTextRecord {
             name: token_id.to_string(),
@@ -212,17 +441,16 @@ fn mint_and_update() {
         execute(deps.as_mut(), mock_env(), info.clone(), rm_record_msg).unwrap();
     }
     // txt record count should be 0
-    let res = contract
-        .parent
-        .nft_info(deps.as_ref(), token_id.into())
-        .unwrap();
-    assert_eq!(res.extension.records.len(), 0);
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), token_id, None, None, false).unwrap();
+    assert_eq!(records.len(), 0);
 
     // add txt record
     let record = TextRecord {
         name: "test".to_string(),
         value: "test".to_string(),
         verified: None,
+        verified_at: None,
     };
     let add_record_msg = ExecuteMsg::AddTextRecord {
         name: token_id.to_string(),
@@ -242,34 +470,32 @@ fn mint_and_update() {
     );
     // passes
     execute(deps.as_mut(), mock_env(), info.clone(), add_record_msg).unwrap();
-    let res = contract
-        .parent
-        .nft_info(deps.as_ref(), token_id.into())
-        .unwrap();
-    assert_eq!(res.extension.records.len(), 1);
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), token_id, None, None, false).unwrap();
+    assert_eq!(records.len(), 1);
 
     // add another txt record
     let record = TextRecord {
         name: "twitter".to_string(),
         value: "jackdorsey".to_string(),
         verified: None,
+        verified_at: None,
     };
     let add_record_msg = ExecuteMsg::AddTextRecord {
         name: token_id.to_string(),
         record,
     };
     execute(deps.as_mut(), mock_env(), info.clone(), add_record_msg).unwrap();
-    let res = contract
-        .parent
-        .nft_info(deps.as_ref(), token_id.into())
-        .unwrap();
-    assert_eq!(res.extension.records.len(), 2);
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), token_id, None, None, false).unwrap();
+    assert_eq!(records.len(), 2);
 
     // add duplicate record RecordNameAlreadyExist
     let record = TextRecord {
         name: "test".to_string(),
         value: "testtesttest".to_string(),
         verified: None,
+        verified_at: None,
     };
     let add_record_msg = ExecuteMsg::AddTextRecord {
         name: token_id.to_string(),
@@ -287,12 +513,11 @@ fn mint_and_update() {
         record: record.clone(),
     };
     execute(deps.as_mut(), mock_env(), info.clone(), update_record_msg).unwrap();
-    let res = contract
-        .parent
-        .nft_info(deps.as_ref(), token_id.into())
-        .unwrap();
-    assert_eq!(res.extension.records.len(), 2);
-    assert_eq!(res.extension.records[1].value, record.value);
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), token_id, None, None, false).unwrap();
+    assert_eq!(records.len(), 2);
+    // records are ordered by name
+    assert_eq!(records[0].value, record.value);
 
     // rm txt record
     let rm_record_msg = ExecuteMsg::RemoveTextRecord {
@@ -300,25 +525,1490 @@ fn mint_and_update() {
         record_name: record.name,
     };
     execute(deps.as_mut(), mock_env(), info, rm_record_msg).unwrap();
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), token_id, None, None, false).unwrap();
+    assert_eq!(records.len(), 1);
+
+    // nft info still returns the records in the metadata
+    let res: NftInfoResponse<Metadata> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.extension.records, records);
+}
+
+#[test]
+fn subnames() {
+    let contract = Sg721NameContract::default();
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+
+    setup_with_name(&mut deps, "alice", CREATOR);
+
+    // only the parent owner can create subnames
+    let create_msg = ExecuteMsg::CreateSubname {
+        parent: "alice".to_string(),
+        label: "pay".to_string(),
+        owner: IMPOSTER.to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IMPOSTER, &[]),
+        create_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::Base(Unauthorized {}).to_string()
+    );
+    execute(deps.as_mut(), mock_env(), info.clone(), create_msg.clone()).unwrap();
+
+    // can not create the same subname twice
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), create_msg).unwrap_err();
+    assert_eq!(err.to_string(), ContractError::Base(Claimed {}).to_string());
+
+    // labels follow name rules and nesting is not supported
+    for (parent, label) in [("alice", "Pay"), ("alice", "-pay"), ("pay.alice", "dao")] {
+        let msg = ExecuteMsg::CreateSubname {
+            parent: parent.to_string(),
+            label: label.to_string(),
+            owner: CREATOR.to_string(),
+        };
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ContractError::InvalidSubname {}.to_string()
+        );
+    }
+
+    let msg = ExecuteMsg::CreateSubname {
+        parent: "alice".to_string(),
+        label: "dao".to_string(),
+        owner: CREATOR.to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    let subnames: Vec<String> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Subnames {
+                parent: "alice".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(subnames, vec!["dao.alice", "pay.alice"]);
+
+    // the number of subnames is capped so removing them with the parent stays bounded
+    for i in 2..MAX_SUBNAMES {
+        let msg = ExecuteMsg::CreateSubname {
+            parent: "alice".to_string(),
+            label: format!("sub{}", i),
+            owner: CREATOR.to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    }
+    let extra_msg = ExecuteMsg::CreateSubname {
+        parent: "alice".to_string(),
+        label: "extra".to_string(),
+        owner: CREATOR.to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), extra_msg.clone()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::TooManySubnames {}.to_string()
+    );
+
+    // the subname owner manages its own records
+    let add_record_msg = ExecuteMsg::AddTextRecord {
+        name: "pay.alice".to_string(),
+        record: TextRecord::new("twitter", "alice_pay"),
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IMPOSTER, &[]),
+        add_record_msg,
+    )
+    .unwrap();
+    assert_eq!(
+        query_text_records(deps.as_ref(), &mock_env(), "pay.alice", None, None, false)
+            .unwrap()
+            .len(),
+        1
+    );
+
+    // the parent owner can take the subname back, which resets its metadata
+    let transfer_msg = ExecuteMsg::TransferSubname {
+        name: "pay.alice".to_string(),
+        recipient: CREATOR.to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IMPOSTER, &[]),
+        transfer_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::Base(Unauthorized {}).to_string()
+    );
+    execute(deps.as_mut(), mock_env(), info.clone(), transfer_msg).unwrap();
     let res = contract
         .parent
-        .nft_info(deps.as_ref(), token_id.into())
+        .owner_of(deps.as_ref(), mock_env(), "pay.alice".to_string(), false)
         .unwrap();
-    assert_eq!(res.extension.records.len(), 1);
+    assert_eq!(res.owner, CREATOR.to_string());
+    assert_eq!(
+        query_text_records(deps.as_ref(), &mock_env(), "pay.alice", None, None, false)
+            .unwrap()
+            .len(),
+        0
+    );
+
+    // revoke burns the subname
+    let revoke_msg = ExecuteMsg::RevokeSubname {
+        name: "pay.alice".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), revoke_msg).unwrap();
+    assert!(contract
+        .parent
+        .nft_info(deps.as_ref(), "pay.alice".to_string())
+        .is_err());
+    assert_eq!(
+        contract.parent.num_tokens(deps.as_ref()).unwrap().count,
+        MAX_SUBNAMES as u64
+    );
+    execute(deps.as_mut(), mock_env(), info.clone(), extra_msg).unwrap();
+
+    let revoke_msg = ExecuteMsg::RevokeSubname {
+        name: "alice".to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info, revoke_msg).unwrap_err();
+    assert_eq!(err.to_string(), ContractError::NotASubname {}.to_string());
+}
+
+fn chain_address_proof(
+    key: &k256::ecdsa::SigningKey,
+    name: &str,
+    owner: &str,
+    address: &ChainAddress,
+) -> ChainAddressProof {
+    // EVM addresses are derived from the uncompressed key and signed lowercase
+    let compress = address.chain != "eth";
+    let message = to_json_vec(&ChainAddressAttestation {
+        collection: mock_env().contract.address.to_string(),
+        name: name.to_string(),
+        owner: owner.to_string(),
+        chain: address.chain.clone(),
+        address: if compress {
+            address.address.clone()
+        } else {
+            address.address.to_lowercase()
+        },
+    })
+    .unwrap();
+    let signature: k256::ecdsa::Signature = k256::ecdsa::signature::Signer::sign(key, &message);
+    ChainAddressProof {
+        pub_key: Binary::from(key.verifying_key().to_encoded_point(compress).as_bytes()),
+        signature: Binary::from(&signature.to_bytes()[..]),
+    }
+}
+
+fn bech32_address(prefix: &str, key: &k256::ecdsa::SigningKey) -> String {
+    let pub_key = key.verifying_key().to_encoded_point(true);
+    bech32::encode(
+        prefix,
+        Ripemd160::digest(Sha256::digest(pub_key.as_bytes())),
+    )
 }
 
 #[test]
-fn query_names() {
-    let deps = mock_deps();
-    let address = "stars1y54exmx84cqtasvjnskf9f63djuuj68p2th570".to_string();
-    let err = query_name(deps.as_ref(), address.clone()).unwrap_err();
+fn chain_addresses() {
+    let mut deps = mock_deps();
+
+    // the owner is the address of a key, the other addresses have keys of their own
+    let owner_key = k256::ecdsa::SigningKey::from_slice(&[4u8; 32]).unwrap();
+    let eth_key = k256::ecdsa::SigningKey::from_slice(&[5u8; 32]).unwrap();
+    let osmo_key = k256::ecdsa::SigningKey::from_slice(&[6u8; 32]).unwrap();
+    let owner = bech32_address("stars", &owner_key);
+    let info = mock_info(&owner, &[]);
+
+    setup_with_name(&mut deps, "alice", &owner);
+    mint_name(&mut deps, "carol", &owner);
+    mint_name(&mut deps, "bob", IMPOSTER);
+
+    let osmo_addr = bech32_address("osmo", &owner_key);
+    let osmo_addr2 = bech32_address("osmo", &osmo_key);
+    let eth_pub_key = eth_key.verifying_key().to_encoded_point(false);
+    let eth_addr = format!(
+        "0x{}",
+        Keccak256::digest(&eth_pub_key.as_bytes()[1..])[12..]
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<String>()
+    );
+
+    // same key as the owner, no proof needed
+    let add_msg = ExecuteMsg::AddChainAddress {
+        name: "alice".to_string(),
+        address: ChainAddress::new("osmo", &osmo_addr),
+        proof: None,
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), add_msg.clone()).unwrap();
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), add_msg).unwrap_err();
     assert_eq!(
         err.to_string(),
-        StdError::GenericErr {
-            msg: format!("No name associated with address {}", address)
-        }
-        .to_string()
+        ContractError::ChainAddressAlreadyExists {}.to_string()
+    );
+
+    // the chain has to match the address
+    for address in [
+        ChainAddress::new("juno", &osmo_addr),
+        ChainAddress::new("eth", "0x1234"),
+        ChainAddress::new("stars", "stars1y54exmx84cqtasvjnskf9f63djuuj68p2th570"),
+    ] {
+        let msg = ExecuteMsg::AddChainAddress {
+            name: "alice".to_string(),
+            address,
+            proof: None,
+        };
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ContractError::InvalidChainAddress {}.to_string()
+        );
+    }
+
+    // other keys have to sign for the name and its owner
+    let eth_address = ChainAddress::new("eth", &eth_addr);
+    for proof in [
+        None,
+        Some(chain_address_proof(
+            &osmo_key,
+            "alice",
+            &owner,
+            &eth_address,
+        )),
+        Some(chain_address_proof(&eth_key, "carol", &owner, &eth_address)),
+        Some(chain_address_proof(
+            &eth_key,
+            "alice",
+            IMPOSTER,
+            &eth_address,
+        )),
+    ] {
+        let msg = ExecuteMsg::AddChainAddress {
+            name: "alice".to_string(),
+            address: eth_address.clone(),
+            proof,
+        };
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ContractError::InvalidChainAddressProof {}.to_string()
+        );
+    }
+    let msg = ExecuteMsg::AddChainAddress {
+        name: "alice".to_string(),
+        address: eth_address.clone(),
+        proof: Some(chain_address_proof(&eth_key, "alice", &owner, &eth_address)),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    assert_eq!(
+        query_address_for(deps.as_ref(), "alice", "osmo").unwrap(),
+        osmo_addr
+    );
+    assert_eq!(
+        query_name(deps.as_ref(), osmo_addr.clone()).unwrap(),
+        "alice"
+    );
+    assert_eq!(
+        query_name(deps.as_ref(), eth_addr.clone()).unwrap(),
+        "alice"
+    );
+
+    // EVM addresses are stored lowercase and resolve in any case
+    assert_eq!(
+        query_address_for(deps.as_ref(), "alice", "eth").unwrap(),
+        eth_addr.to_lowercase()
+    );
+    assert_eq!(
+        query_name(deps.as_ref(), eth_addr.to_lowercase()).unwrap(),
+        "alice"
+    );
+    let lower_eth_address = ChainAddress::new("eth", eth_addr.to_lowercase());
+    let msg = ExecuteMsg::AddChainAddress {
+        name: "carol".to_string(),
+        address: lower_eth_address.clone(),
+        proof: Some(chain_address_proof(
+            &eth_key,
+            "carol",
+            &owner,
+            &lower_eth_address,
+        )),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::AddressAlreadyMapped {}.to_string()
+    );
+
+    // addresses of others can not be squatted
+    let msg = ExecuteMsg::AddChainAddress {
+        name: "bob".to_string(),
+        address: ChainAddress::new("osmo", &osmo_addr),
+        proof: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(IMPOSTER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidChainAddressProof {}.to_string()
+    );
+
+    // an address can only resolve to one name
+    let msg = ExecuteMsg::AddChainAddress {
+        name: "carol".to_string(),
+        address: ChainAddress::new("osmo", &osmo_addr),
+        proof: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::AddressAlreadyMapped {}.to_string()
+    );
+
+    let osmo_address2 = ChainAddress::new("osmo", &osmo_addr2);
+    let msg = ExecuteMsg::UpdateChainAddress {
+        name: "alice".to_string(),
+        address: osmo_address2.clone(),
+        proof: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidChainAddressProof {}.to_string()
+    );
+    let msg = ExecuteMsg::UpdateChainAddress {
+        name: "alice".to_string(),
+        address: osmo_address2.clone(),
+        proof: Some(chain_address_proof(
+            &osmo_key,
+            "alice",
+            &owner,
+            &osmo_address2,
+        )),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    assert_eq!(
+        query_address_for(deps.as_ref(), "alice", "osmo").unwrap(),
+        osmo_addr2
+    );
+    assert_eq!(
+        query_name(deps.as_ref(), osmo_addr2.clone()).unwrap(),
+        "alice"
+    );
+    // falls back to the stars mapping, which is not set
+    assert!(query_name(deps.as_ref(), osmo_addr).is_err());
+
+    let msg = ExecuteMsg::RemoveChainAddress {
+        name: "alice".to_string(),
+        chain: "eth".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    assert!(query_address_for(deps.as_ref(), "alice", "eth").is_err());
+    assert!(query_name(deps.as_ref(), eth_addr).is_err());
+
+    // the new owner doesn't control the addresses of the previous one
+    let msg = ExecuteMsg::SetNameMarketplace {
+        address: "marketplace".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &[]), msg).unwrap();
+    let msg = ExecuteMsg::TransferWithMetadata {
+        recipient: IMPOSTER.to_string(),
+        token_id: "alice".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert!(query_address_for(deps.as_ref(), "alice", "osmo").is_err());
+    assert!(query_name(deps.as_ref(), osmo_addr2).is_err());
+}
+
+#[test]
+fn managers() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+    let manager = "manager";
+    let name = "alice";
+
+    setup_with_name(&mut deps, name, CREATOR);
+
+    let record = TextRecord::new("discord", "alice#0001");
+    let add_record_msg = ExecuteMsg::AddTextRecord {
+        name: name.to_string(),
+        record: record.clone(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(manager, &[]),
+        add_record_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::Base(Unauthorized {}).to_string()
+    );
+
+    // only the owner can add a manager
+    let add_manager_msg = ExecuteMsg::AddManager {
+        name: name.to_string(),
+        manager: manager.to_string(),
+        expires: None,
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(manager, &[]),
+        add_manager_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::Base(Unauthorized {}).to_string()
+    );
+    execute(deps.as_mut(), mock_env(), info.clone(), add_manager_msg).unwrap();
+    let managers = query_managers(deps.as_ref(), name).unwrap();
+    assert_eq!(managers.len(), 1);
+    assert_eq!(managers[0].manager, manager);
+    assert_eq!(managers[0].expires, Expiration::Never {});
+
+    // manager can update records and associate the name with the owner
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(manager, &[]),
+        add_record_msg,
+    )
+    .unwrap();
+    let records = query_text_records(deps.as_ref(), &mock_env(), name, None, None, false).unwrap();
+    assert_eq!(records, vec![record]);
+
+    let msg = ExecuteMsg::AssociateAddress {
+        name: name.to_string(),
+        address: Some(CREATOR.to_string()),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(manager, &[]), msg).unwrap();
+    assert_eq!(
+        query_associated_address(deps.as_ref(), name).unwrap(),
+        CREATOR
     );
+
+    // but can't associate the name with itself
+    let msg = ExecuteMsg::AssociateAddress {
+        name: name.to_string(),
+        address: Some(manager.to_string()),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info(manager, &[]), msg);
+    assert!(res.is_err());
+
+    // expired managers lose their rights
+    let expires = mock_env().block.time.plus_seconds(60);
+    let msg = ExecuteMsg::AddManager {
+        name: name.to_string(),
+        manager: manager.to_string(),
+        expires: Some(Expiration::AtTime(expires)),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let mut env = mock_env();
+    env.block.time = expires;
+    let msg = ExecuteMsg::RemoveTextRecord {
+        name: name.to_string(),
+        record_name: "discord".to_string(),
+    };
+    let err = execute(deps.as_mut(), env, mock_info(manager, &[]), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::Base(Unauthorized {}).to_string()
+    );
+
+    let msg = ExecuteMsg::RemoveManager {
+        name: name.to_string(),
+        manager: manager.to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert!(query_managers(deps.as_ref(), name).unwrap().is_empty());
+}
+
+#[test]
+fn verify_with_signature() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+    let name = "alice";
+
+    setup_with_name(&mut deps, name, CREATOR);
+
+    let twitter = TextRecord::new("twitter", "alice");
+    let github = TextRecord::new("github", "alice");
+    for record in [twitter.clone(), github.clone()] {
+        let msg = ExecuteMsg::AddTextRecord {
+            name: name.to_string(),
+            record,
+        };
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    }
+
+    let secp_key = k256::ecdsa::SigningKey::from_slice(&[1u8; 32]).unwrap();
+    let secp_pub_key = Binary::from(
+        secp_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec(),
+    );
+    let ed_key = ed25519_zebra::SigningKey::from([2u8; 32]);
+    let ed_pub_key = Binary::from(ed25519_zebra::VerificationKey::from(&ed_key).as_ref());
+
+    for (record_name, key_type, pub_key) in [
+        ("twitter", KeyType::Secp256k1, secp_pub_key.clone()),
+        ("github", KeyType::Ed25519, ed_pub_key.clone()),
+    ] {
+        let msg = SudoMsg::AddAttestor {
+            record_name: record_name.to_string(),
+            attestor: Attestor { key_type, pub_key },
+        };
+        sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    }
+    assert_eq!(
+        query_attestors(deps.as_ref(), "twitter").unwrap(),
+        vec![Attestor {
+            key_type: KeyType::Secp256k1,
+            pub_key: secp_pub_key.clone(),
+        }]
+    );
+
+    let attestation = |record: &TextRecord, issued_at: Timestamp| {
+        to_json_vec(&TextRecordAttestation {
+            collection: mock_env().contract.address.to_string(),
+            name: name.to_string(),
+            owner: CREATOR.to_string(),
+            record_name: record.name.clone(),
+            value: record.value.clone(),
+            issued_at,
+        })
+        .unwrap()
+    };
+
+    let issued_at = mock_env().block.time.minus_seconds(60);
+    let secp_signature: k256::ecdsa::Signature =
+        k256::ecdsa::signature::Signer::sign(&secp_key, &attestation(&twitter, issued_at));
+    let ed_signature = ed_key.sign(&attestation(&github, issued_at));
+
+    // attestors are scoped to a record name
+    let msg = ExecuteMsg::VerifyTextRecordWithSignature {
+        name: name.to_string(),
+        record: github.clone(),
+        issued_at,
+        pub_key: secp_pub_key.clone(),
+        signature: Binary::from(&secp_signature.to_bytes()[..]),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(IMPOSTER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::UnknownAttestor {}.to_string()
+    );
+
+    // signature has to match the record value
+    let msg = ExecuteMsg::VerifyTextRecordWithSignature {
+        name: name.to_string(),
+        record: TextRecord::new("twitter", "bob"),
+        issued_at,
+        pub_key: secp_pub_key.clone(),
+        signature: Binary::from(&secp_signature.to_bytes()[..]),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(IMPOSTER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidAttestation {}.to_string()
+    );
+
+    // signatures can't be issued in the future
+    let future = mock_env().block.time.plus_seconds(1);
+    let future_signature: k256::ecdsa::Signature =
+        k256::ecdsa::signature::Signer::sign(&secp_key, &attestation(&twitter, future));
+    let msg = ExecuteMsg::VerifyTextRecordWithSignature {
+        name: name.to_string(),
+        record: twitter.clone(),
+        issued_at: future,
+        pub_key: secp_pub_key.clone(),
+        signature: Binary::from(&future_signature.to_bytes()[..]),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(IMPOSTER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidAttestation {}.to_string()
+    );
+
+    // anyone can submit a valid proof
+    let verify_twitter = ExecuteMsg::VerifyTextRecordWithSignature {
+        name: name.to_string(),
+        record: twitter,
+        issued_at,
+        pub_key: secp_pub_key,
+        signature: Binary::from(&secp_signature.to_bytes()[..]),
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IMPOSTER, &[]),
+        verify_twitter.clone(),
+    )
+    .unwrap();
+    assert!(query_is_verified(deps.as_ref(), &mock_env(), name, "twitter").unwrap());
+    // the verification dates from the signature
+    let record = query_text_record(deps.as_ref(), &mock_env(), name, "twitter").unwrap();
+    assert_eq!(record.unwrap().verified_at, Some(issued_at));
+
+    let msg = ExecuteMsg::VerifyTextRecordWithSignature {
+        name: name.to_string(),
+        record: github,
+        issued_at,
+        pub_key: ed_pub_key.clone(),
+        signature: Binary::from(<[u8; 64]>::from(ed_signature).as_slice()),
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let records = query_text_records(deps.as_ref(), &mock_env(), name, None, None, false).unwrap();
+    assert!(records.iter().all(|r| r.verified == Some(true)));
+
+    // signatures older than the verification period can't be replayed
+    let msg = SudoMsg::UpdateVerificationPeriod { period: Some(3600) };
+    sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    let mut env = mock_env();
+    env.block.time = issued_at.plus_seconds(3600);
+    assert!(!query_is_verified(deps.as_ref(), &env, name, "twitter").unwrap());
+    let res: NftInfoResponse<Metadata> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::NftInfo {
+                token_id: name.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let twitter = res.extension.records.iter().find(|r| r.name == "twitter");
+    assert_eq!(twitter.unwrap().verified, None);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(IMPOSTER, &[]),
+        verify_twitter,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::AttestationExpired {}.to_string()
+    );
+
+    // updating other params keeps the period
+    let msg = SudoMsg::UpdateParams {
+        max_record_count: 20,
+    };
+    sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    let params: SudoParams =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Params {}).unwrap()).unwrap();
+    assert_eq!(params.max_record_count, 20);
+    assert_eq!(params.verification_period, Some(3600));
+
+    // and the period can be cleared so verifications never expire again
+    let msg = SudoMsg::UpdateVerificationPeriod { period: None };
+    sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    assert!(query_is_verified(deps.as_ref(), &env, name, "twitter").unwrap());
+
+    let msg = SudoMsg::RemoveAttestor {
+        record_name: "github".to_string(),
+        pub_key: ed_pub_key,
+    };
+    sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    assert!(query_attestors(deps.as_ref(), "github").unwrap().is_empty());
+}
+
+#[test]
+fn batch_update() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+    let name = "alice";
+
+    setup_with_name(&mut deps, name, CREATOR);
+
+    // fill up to the max record count
+    let ops = (0..10)
+        .map(|i| RecordOp::AddTextRecord {
+            record: TextRecord::new(format!("record{}", i), "value"),
+        })
+        .chain([
+            RecordOp::UpdateImageNft {
+                nft: Some(NFT {
+                    collection: Addr::unchecked("contract"),
+                    token_id: "token_id".to_string(),
+                }),
+            },
+            RecordOp::AssociateAddress {
+                address: Some(CREATOR.to_string()),
+            },
+        ])
+        .collect::<Vec<_>>();
+    let msg = ExecuteMsg::BatchUpdate {
+        name: name.to_string(),
+        ops,
+    };
+    let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    assert_eq!(res.events.len(), 1);
+    assert_eq!(
+        query_text_records(deps.as_ref(), &mock_env(), name, None, None, false)
+            .unwrap()
+            .len(),
+        10
+    );
+    assert_eq!(
+        query_associated_address(deps.as_ref(), name).unwrap(),
+        CREATOR
+    );
+
+    // the record count is only checked after all operations
+    let msg = ExecuteMsg::BatchUpdate {
+        name: name.to_string(),
+        ops: vec![
+            RecordOp::AddTextRecord {
+                record: TextRecord::new("twitter", "alice"),
+            },
+            RecordOp::RemoveTextRecord {
+                record_name: "record0".to_string(),
+            },
+            RecordOp::UpdateTextRecord {
+                record: TextRecord::new("record1", "new value"),
+            },
+        ],
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let records = query_text_records(deps.as_ref(), &mock_env(), name, None, None, false).unwrap();
+    assert_eq!(records.len(), 10);
+    assert!(records.iter().any(|r| r.name == "twitter"));
+    assert!(records.iter().all(|r| r.name != "record0"));
+    assert!(records
+        .iter()
+        .any(|r| r.name == "record1" && r.value == "new value"));
+
+    let msg = ExecuteMsg::BatchUpdate {
+        name: name.to_string(),
+        ops: vec![RecordOp::AddTextRecord {
+            record: TextRecord::new("discord", "alice"),
+        }],
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::TooManyRecords { max: 10 }.to_string()
+    );
+
+    // a failing operation fails the whole batch
+    let msg = ExecuteMsg::BatchUpdate {
+        name: name.to_string(),
+        ops: vec![
+            RecordOp::RemoveTextRecord {
+                record_name: "twitter".to_string(),
+            },
+            RecordOp::AddTextRecord {
+                record: TextRecord::new("record2", "value"),
+            },
+        ],
+    };
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::RecordNameAlreadyExists {}.to_string()
+    );
+    let records = query_text_records(deps.as_ref(), &mock_env(), name, None, None, false).unwrap();
+    assert!(records.iter().any(|r| r.name == "twitter"));
+
+    let msg = ExecuteMsg::BatchUpdate {
+        name: name.to_string(),
+        ops: vec![],
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(IMPOSTER, &[]), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::Base(Unauthorized {}).to_string()
+    );
+}
+
+#[test]
+fn text_record_queries() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+    let name = "alice";
+
+    let mut msg = init_msg();
+    msg.verifier = Some(CREATOR.to_string());
+    instantiate(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    mint_name(&mut deps, name, CREATOR);
+
+    let msg = ExecuteMsg::BatchUpdate {
+        name: name.to_string(),
+        ops: ["discord", "github", "twitter"]
+            .into_iter()
+            .map(|record_name| RecordOp::AddTextRecord {
+                record: TextRecord::new(record_name, "alice"),
+            })
+            .collect(),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::VerifyTextRecord {
+        name: name.to_string(),
+        record_name: "github".to_string(),
+        result: true,
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    // pages are ordered by record name
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), name, None, Some(2), false).unwrap();
+    assert_eq!(
+        records.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(),
+        vec!["discord", "github"]
+    );
+    let records = query_text_records(
+        deps.as_ref(),
+        &mock_env(),
+        name,
+        Some("github".to_string()),
+        None,
+        false,
+    )
+    .unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].name, "twitter");
+
+    let records = query_text_records(deps.as_ref(), &mock_env(), name, None, None, true).unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].name, "github");
+
+    let record = query_text_record(deps.as_ref(), &mock_env(), name, "twitter").unwrap();
+    assert_eq!(record, Some(TextRecord::new("twitter", "alice")));
+    let record = query_text_record(deps.as_ref(), &mock_env(), name, "telegram").unwrap();
+    assert_eq!(record, None);
+
+    assert!(query_is_verified(deps.as_ref(), &mock_env(), name, "github").unwrap());
+    assert!(!query_is_verified(deps.as_ref(), &mock_env(), name, "twitter").unwrap());
+    assert!(!query_is_verified(deps.as_ref(), &mock_env(), name, "telegram").unwrap());
+}
+
+#[test]
+fn record_lookup() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+
+    let mut msg = init_msg();
+    msg.verifier = Some(CREATOR.to_string());
+    instantiate(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    // handles are stored lowercase
+    for (name, value) in [("alice", "Alice"), ("bob", "ALICE")] {
+        mint_name(&mut deps, name, CREATOR);
+        let msg = ExecuteMsg::AddTextRecord {
+            name: name.to_string(),
+            record: TextRecord::new("twitter", value),
+        };
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    }
+    let record = query_text_record(deps.as_ref(), &mock_env(), "bob", "twitter").unwrap();
+    assert_eq!(record, Some(TextRecord::new("twitter", "alice")));
+
+    let name_by_record = |deps: Deps, verified_only: bool| {
+        query_name_by_record(deps, &mock_env(), "twitter", "aLiCe", verified_only)
+    };
+    assert_eq!(name_by_record(deps.as_ref(), false).unwrap(), "alice");
+    assert!(name_by_record(deps.as_ref(), true).is_err());
+
+    let verify = |name: &str| ExecuteMsg::VerifyTextRecord {
+        name: name.to_string(),
+        record_name: "twitter".to_string(),
+        result: true,
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), verify("bob")).unwrap();
+    assert_eq!(name_by_record(deps.as_ref(), true).unwrap(), "bob");
+
+    // only one name can have the handle verified
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), verify("alice")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::RecordAlreadyVerified {}.to_string()
+    );
+
+    // removing the record frees the handle
+    let msg = ExecuteMsg::RemoveTextRecord {
+        name: "bob".to_string(),
+        record_name: "twitter".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    execute(deps.as_mut(), mock_env(), info, verify("alice")).unwrap();
+    assert_eq!(name_by_record(deps.as_ref(), true).unwrap(), "alice");
+}
+
+#[test]
+fn text_record_formats() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+    let name = "alice";
+
+    setup_with_name(&mut deps, name, CREATOR);
+
+    let valid = [
+        ("url", "https://stargaze.zone"),
+        (
+            "avatar",
+            "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        ("email", "alice@stargaze.zone"),
+        ("twitter", "alice_42"),
+        ("github", "alice-dev"),
+        ("discord", "alice.42"),
+        ("telegram", "alice_tg"),
+        ("pubkey", "A08EGB7ro1ORuFhjOnZcSgwYlpe0DSFjVNUIkNNQxwKQ"),
+        ("contenthash", "0xe30101701220"),
+        ("bio", "free text, anything goes!"),
+    ];
+    for (record_name, value) in valid {
+        let msg = ExecuteMsg::AddTextRecord {
+            name: name.to_string(),
+            record: TextRecord::new(record_name, value),
+        };
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    }
+
+    let invalid = [
+        ("url", "stargaze.zone", ContractError::InvalidUrl {}),
+        ("url", "ftp://stargaze.zone", ContractError::InvalidUrl {}),
+        ("avatar", "https://", ContractError::InvalidAvatar {}),
+        ("email", "alice@stargaze", ContractError::InvalidEmail {}),
+        (
+            "email",
+            "alice stargaze.zone",
+            ContractError::InvalidEmail {},
+        ),
+        ("twitter", "@alice", ContractError::InvalidHandle {}),
+        ("github", "-alice", ContractError::InvalidHandle {}),
+        ("discord", "a", ContractError::InvalidHandle {}),
+        ("telegram", "ali", ContractError::InvalidHandle {}),
+        ("pubkey", "not a key", ContractError::InvalidPubKey {}),
+        (
+            "contenthash",
+            "0xe3010",
+            ContractError::InvalidContentHash {},
+        ),
+    ];
+    for (record_name, value, expected) in invalid {
+        let msg = ExecuteMsg::UpdateTextRecord {
+            name: name.to_string(),
+            record: TextRecord::new(record_name, value),
+        };
+        let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+        assert_eq!(err.to_string(), expected.to_string());
+    }
+}
+
+#[test]
+fn content_hash() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+    let name = "alice";
+
+    setup_with_name(&mut deps, name, CREATOR);
+
+    // sha2-256 multihash
+    let mut hash = vec![0x12, 0x20];
+    hash.extend([7u8; 32]);
+    let content_hash = ContentHash {
+        codec: ContentCodec::Ipfs,
+        hash: Binary::from(hash),
+    };
+    let msg = ExecuteMsg::UpdateContentHash {
+        name: name.to_string(),
+        content_hash: Some(content_hash.clone()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IMPOSTER, &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::Base(Unauthorized {}).to_string()
+    );
+    let res = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let metadata = Metadata {
+        content_hash: Some(content_hash.clone()),
+        ..Metadata::default()
+    };
+    assert_eq!(
+        res.events[0].attributes[2].value,
+        metadata.into_json_string()
+    );
+    assert_eq!(
+        query_content_hash(deps.as_ref(), name).unwrap(),
+        Some(content_hash)
+    );
+
+    // digest length does not match the multihash header
+    let msg = ExecuteMsg::UpdateContentHash {
+        name: name.to_string(),
+        content_hash: Some(ContentHash {
+            codec: ContentCodec::Ipfs,
+            hash: Binary::from([0x12, 0x20, 0x01]),
+        }),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidContentHash {}.to_string()
+    );
+
+    let msg = ExecuteMsg::UpdateContentHash {
+        name: name.to_string(),
+        content_hash: Some(ContentHash {
+            codec: ContentCodec::Arweave,
+            hash: Binary::from([1u8; 32]),
+        }),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    // clear
+    let msg = ExecuteMsg::UpdateContentHash {
+        name: name.to_string(),
+        content_hash: None,
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(query_content_hash(deps.as_ref(), name).unwrap(), None);
+}
+
+#[test]
+fn pub_keys() {
+    let mut deps = mock_deps();
+    let name = "alice";
+
+    // the owner is the address of the signing key
+    let signing_key = k256::ecdsa::SigningKey::from_slice(&[3u8; 32]).unwrap();
+    let signer = Binary::from(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec(),
+    );
+    let owner = bech32::encode("stars", Ripemd160::digest(Sha256::digest(&signer)));
+    let info = mock_info(&owner, &[]);
+
+    setup_with_name(&mut deps, name, &owner);
+
+    let pub_key = PubKey {
+        algo: KeyAlgo::X25519,
+        key: Binary::from([9u8; 32]),
+    };
+    let message = to_json_vec(&PubKeyAttestation {
+        collection: mock_env().contract.address.to_string(),
+        name: name.to_string(),
+        address: owner.clone(),
+        pub_key: pub_key.clone(),
+    })
+    .unwrap();
+    let signature: k256::ecdsa::Signature =
+        k256::ecdsa::signature::Signer::sign(&signing_key, &message);
+    let set_msg = ExecuteMsg::SetPubKey {
+        name: name.to_string(),
+        pub_key: pub_key.clone(),
+        signer: signer.clone(),
+        signature: Binary::from(&signature.to_bytes()[..]),
+    };
+
+    // the name needs an associated address
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), set_msg.clone()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::NoAssociatedAddress {}.to_string()
+    );
+    let msg = ExecuteMsg::AssociateAddress {
+        name: name.to_string(),
+        address: Some(owner.clone()),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    execute(deps.as_mut(), mock_env(), info.clone(), set_msg).unwrap();
+    assert_eq!(
+        query_pub_keys(deps.as_ref(), name).unwrap(),
+        vec![pub_key.clone()]
+    );
+
+    // the proof is bound to the key
+    let msg = ExecuteMsg::SetPubKey {
+        name: name.to_string(),
+        pub_key: PubKey {
+            algo: KeyAlgo::X25519,
+            key: Binary::from([8u8; 32]),
+        },
+        signer,
+        signature: Binary::from(&signature.to_bytes()[..]),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidPubKeyProof {}.to_string()
+    );
+
+    let msg = ExecuteMsg::RemovePubKey {
+        name: name.to_string(),
+        algo: KeyAlgo::X25519,
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap();
+    assert!(query_pub_keys(deps.as_ref(), name).unwrap().is_empty());
+    let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::PubKeyNotFound {}.to_string()
+    );
+}
+
+#[test]
+fn aliases() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+
+    setup_with_name(&mut deps, "brand", CREATOR);
+    for name in ["brand2", "main"] {
+        mint_name(&mut deps, name, CREATOR);
+    }
+
+    let msgs = [
+        ExecuteMsg::AssociateAddress {
+            name: "main".to_string(),
+            address: Some(CREATOR.to_string()),
+        },
+        ExecuteMsg::AddTextRecord {
+            name: "main".to_string(),
+            record: TextRecord::new("twitter", "main"),
+        },
+        ExecuteMsg::AddTextRecord {
+            name: "brand".to_string(),
+            record: TextRecord::new("twitter", "brand"),
+        },
+    ];
+    for msg in msgs {
+        execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    }
+    let nft = NFT {
+        collection: Addr::unchecked("contract"),
+        token_id: "token_id".to_string(),
+    };
+    let msg = ExecuteMsg::UpdateImageNft {
+        name: "main".to_string(),
+        nft: Some(nft.clone()),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    VERIFIERS
+        .save(
+            deps.as_mut().storage,
+            (ALL_RECORDS, &Addr::unchecked(CREATOR)),
+            &Empty {},
+        )
+        .unwrap();
+    let msg = ExecuteMsg::VerifyTextRecord {
+        name: "main".to_string(),
+        record_name: "twitter".to_string(),
+        result: true,
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    let msg = ExecuteMsg::SetAlias {
+        name: "brand".to_string(),
+        target: Some("main".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(IMPOSTER, &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::Base(Unauthorized {}).to_string()
+    );
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    let msg = ExecuteMsg::SetAlias {
+        name: "brand2".to_string(),
+        target: Some("brand".to_string()),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    // resolution follows the aliases
+    for name in ["brand", "brand2"] {
+        assert_eq!(
+            query_associated_address(deps.as_ref(), name).unwrap(),
+            CREATOR
+        );
+        let records =
+            query_text_records(deps.as_ref(), &mock_env(), name, None, None, false).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].value, "main");
+
+        let record = query_text_record(deps.as_ref(), &mock_env(), name, "twitter").unwrap();
+        assert_eq!(record.unwrap().value, "main");
+        assert!(query_is_verified(deps.as_ref(), &mock_env(), name, "twitter").unwrap());
+        let status = query_image_nft_status(deps.as_ref(), name).unwrap();
+        assert_eq!(status.nft, Some(nft.clone()));
+        assert!(status.owned);
+    }
+    assert_eq!(
+        query_alias_of(deps.as_ref(), "brand2").unwrap(),
+        Some("brand".to_string())
+    );
+
+    let msg = ExecuteMsg::SetAlias {
+        name: "main".to_string(),
+        target: Some("brand2".to_string()),
+    };
+    let err = execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert_eq!(err.to_string(), ContractError::AliasCycle {}.to_string());
+
+    // the alias keeps its own records once it stops forwarding
+    let msg = ExecuteMsg::SetAlias {
+        name: "brand".to_string(),
+        target: None,
+    };
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    let records =
+        query_text_records(deps.as_ref(), &mock_env(), "brand2", None, None, false).unwrap();
+    assert_eq!(records, vec![TextRecord::new("twitter", "brand")]);
+}
+
+#[test]
+fn association_history() {
+    let mut deps = mock_deps();
+    let info = mock_info(CREATOR, &[]);
+
+    setup_with_name(&mut deps, "first", CREATOR);
+    mint_name(&mut deps, "second", CREATOR);
+
+    // creator moves from "first" to "second", then removes the association
+    let changes = [
+        ("first", Some(CREATOR)),
+        ("second", Some(CREATOR)),
+        ("second", None),
+    ];
+    let mut env = mock_env();
+    let start = env.block.height;
+    for (name, address) in changes {
+        env.block.height += 1;
+        let msg = ExecuteMsg::AssociateAddress {
+            name: name.to_string(),
+            address: address.map(|a| a.to_string()),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    }
+
+    let creator = Some(Addr::unchecked(CREATOR));
+    assert_eq!(
+        query_association_history(deps.as_ref(), "first", None, None).unwrap(),
+        vec![
+            AssociationResponse {
+                height: start + 1,
+                address: creator.clone(),
+            },
+            AssociationResponse {
+                height: start + 2,
+                address: None,
+            },
+        ]
+    );
+    assert_eq!(
+        query_association_history(deps.as_ref(), "second", Some(start + 2), None).unwrap(),
+        vec![AssociationResponse {
+            height: start + 3,
+            address: None,
+        }]
+    );
+
+    let name_at = |deps: Deps, height| query_name_at(deps, CREATOR.to_string(), height).unwrap();
+    assert_eq!(name_at(deps.as_ref(), start), None);
+    assert_eq!(name_at(deps.as_ref(), start + 1), Some("first".to_string()));
+    assert_eq!(
+        name_at(deps.as_ref(), start + 2),
+        Some("second".to_string())
+    );
+    assert_eq!(name_at(deps.as_ref(), start + 10), None);
+
+    // only the latest changes are kept
+    let msg = SudoMsg::UpdateMaxHistoryCount { count: Some(2) };
+    sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    // other params can be updated without resetting the count
+    let msg = SudoMsg::UpdateParams {
+        max_record_count: 10,
+    };
+    sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    let params: SudoParams =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Params {}).unwrap()).unwrap();
+    assert_eq!(params.max_history_count, Some(2));
+    env.block.height += 1;
+    let msg = ExecuteMsg::AssociateAddress {
+        name: "second".to_string(),
+        address: Some(CREATOR.to_string()),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let history = query_association_history(deps.as_ref(), "second", None, None).unwrap();
+    assert_eq!(
+        history.iter().map(|h| h.height).collect::<Vec<_>>(),
+        vec![start + 3, start + 4]
+    );
+    assert_eq!(
+        query_name_at(deps.as_ref(), CREATOR.to_string(), start + 1).unwrap(),
+        None
+    );
+    assert_eq!(
+        query_name_at(deps.as_ref(), CREATOR.to_string(), start + 4).unwrap(),
+        Some("second".to_string())
+    );
+    // and the count can be reset to the default
+    let msg = SudoMsg::UpdateMaxHistoryCount { count: None };
+    sudo(deps.as_mut(), mock_env(), msg).unwrap();
+    let params: SudoParams =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Params {}).unwrap()).unwrap();
+    assert_eq!(params.max_history_count, None);
+
+    // the association also ends when the name is transferred or burned
+    let marketplace = mock_info("marketplace", &[]);
+    let msg = ExecuteMsg::SetNameMarketplace {
+        address: marketplace.sender.to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+    env.block.height += 1;
+    let msg = ExecuteMsg::TransferNft {
+        recipient: IMPOSTER.to_string(),
+        token_id: "second".to_string(),
+    };
+    execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let history = query_association_history(deps.as_ref(), "second", None, None).unwrap();
+    assert_eq!(
+        history.last(),
+        Some(&AssociationResponse {
+            height: start + 5,
+            address: None,
+        })
+    );
+    assert_eq!(name_at(deps.as_ref(), start + 5), None);
+
+    env.block.height += 1;
+    let msg = ExecuteMsg::AssociateAddress {
+        name: "first".to_string(),
+        address: Some(CREATOR.to_string()),
+    };
+    execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+    assert_eq!(name_at(deps.as_ref(), start + 6), Some("first".to_string()));
+    env.block.height += 1;
+    let msg = ExecuteMsg::Burn {
+        token_id: "first".to_string(),
+    };
+    execute(deps.as_mut(), env, marketplace, msg).unwrap();
+    let history = query_association_history(deps.as_ref(), "first", None, None).unwrap();
+    assert_eq!(
+        history.last(),
+        Some(&AssociationResponse {
+            height: start + 7,
+            address: None,
+        })
+    );
+    assert_eq!(name_at(deps.as_ref(), start + 7), None);
+}
+#[test]
+fn query_names() {
+    let deps = mock_deps();
+    let address = "stars1y54exmx84cqtasvjnskf9f63djuuj68p2th570".to_string();
+    let err = query_name(deps.as_ref(), address.clone()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        StdError::GenericErr {
+            msg: format!("No name associated with address {}", address)
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn batch_resolution() {
+    let mut deps = mock_deps();
+    let owner = "stars1y54exmx84cqtasvjnskf9f63djuuj68p2th570";
+    let unmapped = "stars1hsk6jryyqjfhp5dhc55tc9jtckygx0eprx6sym";
+
+    setup_with_name(&mut deps, "alice", owner);
+    let msg = ExecuteMsg::AssociateAddress {
+        name: "alice".to_string(),
+        address: Some(owner.to_string()),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(owner, &[]), msg).unwrap();
+
+    // other chains resolve through the transcoded stars address
+    let (_, data) = bech32::decode(owner).unwrap();
+    let cosmos_address = bech32::encode("cosmos", data);
+    // invalid entries don't fail the batch
+    let addresses = vec![
+        owner.to_string(),
+        "not an address".to_string(),
+        cosmos_address.clone(),
+        unmapped.to_string(),
+    ];
+    assert_eq!(
+        contract::query_names(deps.as_ref(), addresses).unwrap(),
+        vec![
+            (owner.to_string(), Some("alice".to_string())),
+            ("not an address".to_string(), None),
+            (cosmos_address, Some("alice".to_string())),
+            (unmapped.to_string(), None),
+        ]
+    );
+
+    // aliases forwarding to each other can't be resolved
+    for (name, target) in [("loop1", "loop2"), ("loop2", "loop1")] {
+        ALIASES
+            .save(deps.as_mut().storage, name, &target.to_string())
+            .unwrap();
+    }
+    let names = vec!["alice".to_string(), "bob".to_string(), "loop1".to_string()];
+    assert_eq!(
+        query_associated_addresses(deps.as_ref(), names).unwrap(),
+        vec![
+            ("alice".to_string(), Some(owner.to_string())),
+            ("bob".to_string(), None),
+            ("loop1".to_string(), None),
+        ]
+    );
+
+    let too_many = vec![owner.to_string(); 101];
+    contract::query_names(deps.as_ref(), too_many).unwrap_err();
 }
 
 #[test]
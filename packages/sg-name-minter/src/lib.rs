@@ -1,5 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
+use sg_name::Metadata;
 
 pub const PUBLIC_MINT_START_TIME_IN_SECONDS: Timestamp = Timestamp::from_seconds(1669406400);
 #[cw_serde]
@@ -12,6 +13,19 @@ pub struct SudoParams {
     pub base_price: Uint128,
     /// Fair Burn fee (rest goes to Community Pool)
     pub fair_burn_percent: Decimal,
+    /// Longest term in years a name can be registered for at mint, 1 if not set
+    pub max_years: Option<u32>,
+    /// Discounts on multi-year registrations, the one with the highest
+    /// `min_years` not above the registered term applies
+    #[serde(default)]
+    pub multi_year_discounts: Vec<MultiYearDiscount>,
+}
+
+#[cw_serde]
+pub struct MultiYearDiscount {
+    pub min_years: u32,
+    /// Percent off the price of the whole term
+    pub discount: Decimal,
 }
 
 #[cw_serde]
@@ -19,10 +33,53 @@ pub struct Config {
     pub public_mint_start_time: Timestamp,
 }
 
+/// Names nobody can mint, or only a specific address
+#[cw_serde]
+pub struct Reservation {
+    /// The only address that can mint the name, blocked for everyone if not set
+    pub owner: Option<Addr>,
+    /// Paid per year by `owner` instead of the regular price
+    pub price: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct ReservedName {
+    pub name: String,
+    /// The name is blocked for everyone if not set
+    pub owner: Option<String>,
+    pub price: Option<Uint128>,
+}
+
 #[cw_serde]
 pub enum SgNameMinterExecuteMsg {
     /// Mint a name and list on Stargaze Name Marketplace
-    MintAndList { name: String },
+    /// for `years` (1 if not set) before the first renewal.
+    /// The sender pays for a name minted to `owner`, which has to approve the
+    /// marketplace beforehand. `associate` sets `owner` as the associated address,
+    /// only when it is the sender so a gift can't replace the owner's mapping.
+    MintAndList {
+        name: String,
+        years: Option<u32>,
+        owner: Option<String>,
+        metadata: Option<Metadata>,
+        #[serde(default)]
+        associate: bool,
+    },
+    /// Mint and list several names for the sender, paying the sum of their prices
+    BatchMintAndList { names: Vec<String> },
+    /// Commit to minting a name without revealing it
+    CommitMint { commitment: Binary },
+    /// Mint and list a name committed to by the sender with `CommitMint`.
+    /// Takes the same options as `MintAndList`, `owner` is part of the commitment.
+    RevealMint {
+        name: String,
+        salt: String,
+        years: Option<u32>,
+        owner: Option<String>,
+        metadata: Option<Metadata>,
+        #[serde(default)]
+        associate: bool,
+    },
     /// Change the admin that manages the whitelist
     /// Will be set to null after go-to-market
     UpdateAdmin { admin: Option<String> },
@@ -36,6 +93,10 @@ pub enum SgNameMinterExecuteMsg {
     /// Update config, only callable by admin
     /// will not be callable after admin is removed
     UpdateConfig { config: Config },
+    /// Block or reserve names, only callable by admin
+    AddReservedNames { names: Vec<ReservedName> },
+    /// Release blocked or reserved names, only callable by admin
+    RemoveReservedNames { names: Vec<String> },
 }
 
 #[cw_serde]
@@ -51,4 +112,9 @@ pub enum SgNameMinterQueryMsg {
     Params {},
     #[returns(Config)]
     Config {},
+    #[returns(Vec<(String, Reservation)>)]
+    ReservedNames {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
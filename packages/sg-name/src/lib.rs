@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_vec, Addr};
+use cosmwasm_std::{to_json_vec, Addr, Binary, Timestamp};
 
 pub const MAX_TEXT_LENGTH: u32 = 512;
 
@@ -19,9 +19,10 @@ impl NFT {
 
 #[cw_serde]
 pub struct TextRecord {
-    pub name: String,           // "twitter"
-    pub value: String,          // "shan3v"
-    pub verified: Option<bool>, // can only be set by oracle
+    pub name: String,                   // "twitter"
+    pub value: String,                  // "shan3v"
+    pub verified: Option<bool>,         // can only be set by oracle
+    pub verified_at: Option<Timestamp>, // time of the last verification
 }
 
 impl TextRecord {
@@ -30,6 +31,7 @@ impl TextRecord {
             name: name.into(),
             value: value.into(),
             verified: None,
+            verified_at: None,
         }
     }
 
@@ -38,12 +40,89 @@ impl TextRecord {
     }
 }
 
+/// Operation applied by `BatchUpdate`
+#[cw_serde]
+pub enum RecordOp {
+    AddTextRecord { record: TextRecord },
+    UpdateTextRecord { record: TextRecord },
+    RemoveTextRecord { record_name: String },
+    UpdateImageNft { nft: Option<NFT> },
+    AssociateAddress { address: Option<String> },
+}
+
+/// Address of a name on another chain, EVM addresses are stored lowercase
+#[cw_serde]
+pub struct ChainAddress {
+    pub chain: String,   // "osmo", or "eth" for EVM addresses
+    pub address: String, // "osmo1..."
+}
+
+/// Signature proving that the key of a chain address belongs to the name owner
+#[cw_serde]
+pub struct ChainAddressProof {
+    /// secp256k1 key of the address, compressed for bech32 addresses
+    /// and uncompressed for EVM addresses
+    pub pub_key: Binary,
+    /// Signature of the sha256 hash of the attestation encoded as JSON
+    pub signature: Binary,
+}
+
+impl ChainAddress {
+    pub fn new(chain: impl Into<String>, address: impl Into<String>) -> Self {
+        Self {
+            chain: chain.into(),
+            address: address.into(),
+        }
+    }
+
+    pub fn into_json_string(self: &ChainAddress) -> String {
+        String::from_utf8(to_json_vec(&self).unwrap_or_default()).unwrap_or_default()
+    }
+}
+
+/// Protocol that serves the content of a `ContentHash`
+#[cw_serde]
+pub enum ContentCodec {
+    Ipfs,
+    Ipns,
+    Swarm,
+    Arweave,
+}
+
+/// Decentralized website of a name, as ENS contenthash (EIP-1577)
+#[cw_serde]
+pub struct ContentHash {
+    pub codec: ContentCodec,
+    pub hash: Binary, // multihash bytes, or the transaction id for Arweave
+}
+
+#[cw_serde]
+pub enum KeyAlgo {
+    X25519,
+    Secp256k1,
+}
+
+/// Public key wallets use to encrypt messages to a name, at most one per algorithm
+#[cw_serde]
+pub struct PubKey {
+    pub algo: KeyAlgo,
+    pub key: Binary,
+}
+
 /// Note that the address mapped to the name is stored in `token_uri`.
 #[cw_serde]
 #[derive(Default)]
 pub struct Metadata {
     pub image_nft: Option<NFT>,
     pub records: Vec<TextRecord>,
+    /// Addresses on other chains, at most one per chain
+    #[serde(default)]
+    pub addresses: Vec<ChainAddress>,
+    /// Website served by gateways resolving the name
+    pub content_hash: Option<ContentHash>,
+    /// Encryption keys controlled by the associated address
+    #[serde(default)]
+    pub pub_keys: Vec<PubKey>,
 }
 
 impl Metadata {
@@ -69,25 +148,84 @@ pub enum SgNameExecuteMsg {
     },
     /// Update image
     UpdateImageNft { name: String, nft: Option<NFT> },
+    /// Set the content hash of a name's website, or clear it with `None`
+    UpdateContentHash {
+        name: String,
+        content_hash: Option<ContentHash>,
+    },
     /// Update Metadata
     UpdateMetadata {
         name: String,
         metadata: Option<Metadata>,
     },
+    /// Publish an encryption key, with a proof signed by the associated address
+    SetPubKey {
+        name: String,
+        pub_key: PubKey,
+        signer: Binary,
+        signature: Binary,
+    },
+    /// Remove the encryption key for an algorithm
+    RemovePubKey { name: String, algo: KeyAlgo },
+    /// Forward resolution of a name to another name
+    SetAlias {
+        name: String,
+        target: Option<String>,
+    },
+    /// Permanently forbid changes to a name.
+    /// A subname with burned fuses can no longer be revoked by its parent.
+    BurnFuses { name: String, fuses: u32 },
     /// Add text record ex: twitter handle, discord name, etc
     AddTextRecord { name: String, record: TextRecord },
     /// Remove text record ex: twitter handle, discord name, etc
     RemoveTextRecord { name: String, record_name: String },
     /// Update text record ex: twitter handle, discord name, etc
     UpdateTextRecord { name: String, record: TextRecord },
+    /// Apply several metadata updates at once, `max_record_count` is checked after all of them
+    BatchUpdate { name: String, ops: Vec<RecordOp> },
+    /// Transfer a name keeping its records and image
+    TransferWithMetadata { recipient: String, token_id: String },
+    /// Set one of the sender's names as its primary name, or clear it with `None`
+    SetPrimaryName { name: Option<String> },
     /// Verify a text record (via oracle)
     VerifyTextRecord {
         name: String,
         record_name: String,
         result: bool,
     },
-    /// Update the reset the verification oracle
-    UpdateVerifier { verifier: Option<String> },
+    /// Emit an event asking oracles to (re-)verify a text record
+    RequestVerification { name: String, record_name: String },
+    /// Allow an oracle to verify records in a scope ex: twitter, social-*, *
+    AddVerifier { verifier: String, scope: String },
+    /// Remove an oracle from a scope
+    RemoveVerifier { verifier: String, scope: String },
+    /// Add an address for another chain ex: osmo, juno, eth
+    /// `proof` is only needed when the address has another key than the owner
+    AddChainAddress {
+        name: String,
+        address: ChainAddress,
+        proof: Option<ChainAddressProof>,
+    },
+    /// Remove the address for a chain
+    RemoveChainAddress { name: String, chain: String },
+    /// Update the address for a chain
+    UpdateChainAddress {
+        name: String,
+        address: ChainAddress,
+        proof: Option<ChainAddressProof>,
+    },
+    /// Create a subname (i.e: `pay.alice`) under a name owned by the sender
+    CreateSubname {
+        parent: String,
+        label: String,
+        owner: String,
+    },
+    /// Move a subname to a new owner, only callable by the parent name owner
+    TransferSubname { name: String, recipient: String },
+    /// Burn a subname, only callable by the parent name owner.
+    /// A subname with burned fuses can't be revoked, and is kept when the parent
+    /// is sold or burned.
+    RevokeSubname { name: String },
 }
 
 #[cw_serde]
@@ -97,16 +235,53 @@ pub enum SgNameQueryMsg {
     /// converted to a stars address for internal mapping.
     #[returns(String)]
     Name { address: String },
+    #[returns(Vec<(String, Option<String>)>)]
+    Names { addresses: Vec<String> },
     #[returns(Addr)]
     NameMarketplace {},
     #[returns(String)]
     AssociatedAddress { name: String },
+    #[returns(Vec<(String, Option<String>)>)]
+    AssociatedAddresses { names: Vec<String> },
+    #[returns(String)]
+    AddressFor { name: String, chain: String },
     #[returns(Option<NFT>)]
     ImageNFT { name: String },
+    #[returns(Option<ContentHash>)]
+    ContentHash { name: String },
+    #[returns(Vec<PubKey>)]
+    PubKeys { name: String },
+    #[returns(u32)]
+    Fuses { name: String },
+    #[returns(Option<String>)]
+    AliasOf { name: String },
+    #[returns(Option<String>)]
+    NameAt { address: String, height: u64 },
     #[returns(Vec<TextRecord>)]
-    TextRecords { name: String },
+    TextRecords {
+        name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        verified_only: Option<bool>,
+    },
+    #[returns(Option<TextRecord>)]
+    TextRecord { name: String, record_name: String },
     #[returns(bool)]
-    IsTwitterVerified { name: String },
+    IsVerified { name: String, record_name: String },
+    #[returns(Vec<String>)]
+    Subnames {
+        parent: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Option<String>)]
+    PrimaryName { address: String },
+    #[returns(String)]
+    NameByRecord {
+        record_name: String,
+        value: String,
+        verified_only: bool,
+    },
 }
 
 #[cfg(test)]
@@ -132,7 +307,7 @@ mod test {
         let json = record.into_json_string();
         assert_eq!(
             json,
-            r#"{"name":"twitter","value":"shan3v","verified":null}"#
+            r#"{"name":"twitter","value":"shan3v","verified":null,"verified_at":null}"#
         );
 
         record.verified = Some(true);
@@ -140,7 +315,7 @@ mod test {
         let json = record.into_json_string();
         assert_eq!(
             json,
-            r#"{"name":"twitter","value":"shan3v","verified":true}"#
+            r#"{"name":"twitter","value":"shan3v","verified":true,"verified_at":null}"#
         );
 
         record.verified = Some(false);
@@ -148,7 +323,7 @@ mod test {
         let json = record.into_json_string();
         assert_eq!(
             json,
-            r#"{"name":"twitter","value":"shan3v","verified":false}"#
+            r#"{"name":"twitter","value":"shan3v","verified":false,"verified_at":null}"#
         );
     }
 
@@ -162,12 +337,26 @@ mod test {
         let mut record_2 = TextRecord::new("discord", "shan3v");
         record_2.verified = Some(true);
         let records = vec![record_1, record_2];
-        let metadata = Metadata { image_nft, records };
+        let addresses = vec![ChainAddress::new(
+            "osmo",
+            "osmo1y54exmx84cqtasvjnskf9f63djuuj68p7hqf47",
+        )];
+        let content_hash = Some(ContentHash {
+            codec: ContentCodec::Ipfs,
+            hash: Binary::from([0x12, 0x01, 0xff]),
+        });
+        let metadata = Metadata {
+            image_nft,
+            records,
+            addresses,
+            content_hash,
+            pub_keys: vec![],
+        };
 
         let json = metadata.into_json_string();
         assert_eq!(
             json,
-            r#"{"image_nft":{"collection":"stars1y54exmx84cqtasvjnskf9f63djuuj68p2th570","token_id":"1"},"records":[{"name":"twitter","value":"shan3v","verified":null},{"name":"discord","value":"shan3v","verified":true}]}"#,
+            r#"{"image_nft":{"collection":"stars1y54exmx84cqtasvjnskf9f63djuuj68p2th570","token_id":"1"},"records":[{"name":"twitter","value":"shan3v","verified":null,"verified_at":null},{"name":"discord","value":"shan3v","verified":true,"verified_at":null}],"addresses":[{"chain":"osmo","address":"osmo1y54exmx84cqtasvjnskf9f63djuuj68p7hqf47"}],"content_hash":{"codec":"ipfs","hash":"EgH/"},"pub_keys":[]}"#,
         );
     }
 }
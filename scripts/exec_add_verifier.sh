@@ -1,7 +1,8 @@
 MSG=$(cat <<EOF
 {
-  "update_verifier": {
-    "verifier": "$VERIFIER"
+  "add_verifier": {
+    "verifier": "$VERIFIER",
+    "scope": "${SCOPE:-*}"
   }
 }
 EOF
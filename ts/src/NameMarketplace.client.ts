@@ -407,10 +407,12 @@ export interface NameMarketplaceInterface extends NameMarketplaceReadOnlyInterfa
   sender: string;
   setAsk: ({
     seller,
-    tokenId
+    tokenId,
+    years
   }: {
     seller: string;
     tokenId: string;
+    years?: number;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   removeAsk: ({
     tokenId
@@ -500,15 +502,18 @@ export class NameMarketplaceClient extends NameMarketplaceQueryClient implements
 
   setAsk = async ({
     seller,
-    tokenId
+    tokenId,
+    years
   }: {
     seller: string;
     tokenId: string;
+    years?: number;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       set_ask: {
         seller,
-        token_id: tokenId
+        token_id: tokenId,
+        years
       }
     }, fee, memo, funds);
   };
@@ -13,10 +13,12 @@ export interface NameMarketplaceMessage {
   sender: string;
   setAsk: ({
     seller,
-    tokenId
+    tokenId,
+    years
   }: {
     seller: string;
     tokenId: string;
+    years?: number;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   removeAsk: ({
     tokenId
@@ -103,10 +105,12 @@ export class NameMarketplaceMessageComposer implements NameMarketplaceMessage {
 
   setAsk = ({
     seller,
-    tokenId
+    tokenId,
+    years
   }: {
     seller: string;
     tokenId: string;
+    years?: number;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -116,7 +120,8 @@ export class NameMarketplaceMessageComposer implements NameMarketplaceMessage {
         msg: toUtf8(JSON.stringify({
           set_ask: {
             seller,
-            token_id: tokenId
+            token_id: tokenId,
+            years
           }
         })),
         funds
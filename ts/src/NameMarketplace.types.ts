@@ -20,6 +20,7 @@ export type ExecuteMsg = {
   set_ask: {
     seller: string;
     token_id: string;
+    years?: number | null;
   };
 } | {
   remove_ask: {
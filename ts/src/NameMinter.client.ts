@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { Uint128, InstantiateMsg, ExecuteMsg, Timestamp, Uint64, Config, QueryMsg, AdminResponse, Addr, Decimal, SudoParams, ArrayOfAddr } from "./NameMinter.types";
+import { Uint128, InstantiateMsg, ExecuteMsg, ContentCodec, Binary, Addr, KeyAlgo, Timestamp, Uint64, Metadata, ChainAddress, ContentHash, NFT, PubKey, TextRecord, Config, ReservedName, QueryMsg, AdminResponse, CommitRevealParams, NullableCommitment, Commitment, Decimal, SudoParams, MultiYearDiscount, ArrayOfTupleOfStringAndReservation, Reservation, ArrayOfAddr } from "./NameMinter.types";
 export interface NameMinterReadOnlyInterface {
   contractAddress: string;
   admin: () => Promise<AdminResponse>;
@@ -14,6 +14,19 @@ export interface NameMinterReadOnlyInterface {
   collection: () => Promise<Addr>;
   params: () => Promise<SudoParams>;
   config: () => Promise<Config>;
+  commitRevealParams: () => Promise<CommitRevealParams>;
+  commitment: ({
+    commitment
+  }: {
+    commitment: Binary;
+  }) => Promise<NullableCommitment>;
+  reservedNames: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ArrayOfTupleOfStringAndReservation>;
 }
 export class NameMinterQueryClient implements NameMinterReadOnlyInterface {
   client: CosmWasmClient;
@@ -27,6 +40,9 @@ export class NameMinterQueryClient implements NameMinterReadOnlyInterface {
     this.collection = this.collection.bind(this);
     this.params = this.params.bind(this);
     this.config = this.config.bind(this);
+    this.commitRevealParams = this.commitRevealParams.bind(this);
+    this.commitment = this.commitment.bind(this);
+    this.reservedNames = this.reservedNames.bind(this);
   }
 
   admin = async (): Promise<AdminResponse> => {
@@ -54,14 +70,77 @@ export class NameMinterQueryClient implements NameMinterReadOnlyInterface {
       config: {}
     });
   };
+  commitRevealParams = async (): Promise<CommitRevealParams> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      commit_reveal_params: {}
+    });
+  };
+  commitment = async ({
+    commitment
+  }: {
+    commitment: Binary;
+  }): Promise<NullableCommitment> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      commitment: {
+        commitment
+      }
+    });
+  };
+  reservedNames = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ArrayOfTupleOfStringAndReservation> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      reserved_names: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
 }
 export interface NameMinterInterface extends NameMinterReadOnlyInterface {
   contractAddress: string;
   sender: string;
   mintAndList: ({
-    name
+    associate,
+    metadata,
+    name,
+    owner,
+    years
   }: {
+    associate?: boolean;
+    metadata?: Metadata;
     name: string;
+    owner?: string;
+    years?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  batchMintAndList: ({
+    names
+  }: {
+    names: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  commitMint: ({
+    commitment
+  }: {
+    commitment: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revealMint: ({
+    associate,
+    metadata,
+    name,
+    owner,
+    salt,
+    years
+  }: {
+    associate?: boolean;
+    metadata?: Metadata;
+    name: string;
+    owner?: string;
+    salt: string;
+    years?: number;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   updateAdmin: ({
     admin
@@ -90,6 +169,16 @@ export interface NameMinterInterface extends NameMinterReadOnlyInterface {
   }: {
     config: Config;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addReservedNames: ({
+    names
+  }: {
+    names: ReservedName[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeReservedNames: ({
+    names
+  }: {
+    names: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class NameMinterClient extends NameMinterQueryClient implements NameMinterInterface {
   client: SigningCosmWasmClient;
@@ -102,21 +191,86 @@ export class NameMinterClient extends NameMinterQueryClient implements NameMinte
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.mintAndList = this.mintAndList.bind(this);
+    this.batchMintAndList = this.batchMintAndList.bind(this);
+    this.commitMint = this.commitMint.bind(this);
+    this.revealMint = this.revealMint.bind(this);
     this.updateAdmin = this.updateAdmin.bind(this);
     this.pause = this.pause.bind(this);
     this.addWhitelist = this.addWhitelist.bind(this);
     this.removeWhitelist = this.removeWhitelist.bind(this);
     this.updateConfig = this.updateConfig.bind(this);
+    this.addReservedNames = this.addReservedNames.bind(this);
+    this.removeReservedNames = this.removeReservedNames.bind(this);
   }
 
   mintAndList = async ({
-    name
+    associate,
+    metadata,
+    name,
+    owner,
+    years
   }: {
+    associate?: boolean;
+    metadata?: Metadata;
     name: string;
+    owner?: string;
+    years?: number;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       mint_and_list: {
-        name
+        associate,
+        metadata,
+        name,
+        owner,
+        years
+      }
+    }, fee, memo, funds);
+  };
+  batchMintAndList = async ({
+    names
+  }: {
+    names: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      batch_mint_and_list: {
+        names
+      }
+    }, fee, memo, funds);
+  };
+  commitMint = async ({
+    commitment
+  }: {
+    commitment: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      commit_mint: {
+        commitment
+      }
+    }, fee, memo, funds);
+  };
+  revealMint = async ({
+    associate,
+    metadata,
+    name,
+    owner,
+    salt,
+    years
+  }: {
+    associate?: boolean;
+    metadata?: Metadata;
+    name: string;
+    owner?: string;
+    salt: string;
+    years?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reveal_mint: {
+        associate,
+        metadata,
+        name,
+        owner,
+        salt,
+        years
       }
     }, fee, memo, funds);
   };
@@ -178,4 +332,26 @@ export class NameMinterClient extends NameMinterQueryClient implements NameMinte
       }
     }, fee, memo, funds);
   };
+  addReservedNames = async ({
+    names
+  }: {
+    names: ReservedName[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_reserved_names: {
+        names
+      }
+    }, fee, memo, funds);
+  };
+  removeReservedNames = async ({
+    names
+  }: {
+    names: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_reserved_names: {
+        names
+      }
+    }, fee, memo, funds);
+  };
 }
\ No newline at end of file
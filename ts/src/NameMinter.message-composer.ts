@@ -8,14 +8,47 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "cosmwasm";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Uint128, InstantiateMsg, ExecuteMsg, Timestamp, Uint64, Config, QueryMsg, AdminResponse, Addr, Decimal, SudoParams, ArrayOfAddr } from "./NameMinter.types";
+import { Uint128, InstantiateMsg, ExecuteMsg, ContentCodec, Binary, Addr, KeyAlgo, Timestamp, Uint64, Metadata, ChainAddress, ContentHash, NFT, PubKey, TextRecord, Config, ReservedName, QueryMsg, AdminResponse, CommitRevealParams, NullableCommitment, Commitment, Decimal, SudoParams, MultiYearDiscount, ArrayOfTupleOfStringAndReservation, Reservation, ArrayOfAddr } from "./NameMinter.types";
 export interface NameMinterMessage {
   contractAddress: string;
   sender: string;
   mintAndList: ({
-    name
+    associate,
+    metadata,
+    name,
+    owner,
+    years
   }: {
+    associate?: boolean;
+    metadata?: Metadata;
     name: string;
+    owner?: string;
+    years?: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  batchMintAndList: ({
+    names
+  }: {
+    names: string[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  commitMint: ({
+    commitment
+  }: {
+    commitment: Binary;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revealMint: ({
+    associate,
+    metadata,
+    name,
+    owner,
+    salt,
+    years
+  }: {
+    associate?: boolean;
+    metadata?: Metadata;
+    name: string;
+    owner?: string;
+    salt: string;
+    years?: number;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   updateAdmin: ({
     admin
@@ -44,6 +77,16 @@ export interface NameMinterMessage {
   }: {
     config: Config;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addReservedNames: ({
+    names
+  }: {
+    names: ReservedName[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeReservedNames: ({
+    names
+  }: {
+    names: string[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class NameMinterMessageComposer implements NameMinterMessage {
   sender: string;
@@ -53,17 +96,30 @@ export class NameMinterMessageComposer implements NameMinterMessage {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.mintAndList = this.mintAndList.bind(this);
+    this.batchMintAndList = this.batchMintAndList.bind(this);
+    this.commitMint = this.commitMint.bind(this);
+    this.revealMint = this.revealMint.bind(this);
     this.updateAdmin = this.updateAdmin.bind(this);
     this.pause = this.pause.bind(this);
     this.addWhitelist = this.addWhitelist.bind(this);
     this.removeWhitelist = this.removeWhitelist.bind(this);
     this.updateConfig = this.updateConfig.bind(this);
+    this.addReservedNames = this.addReservedNames.bind(this);
+    this.removeReservedNames = this.removeReservedNames.bind(this);
   }
 
   mintAndList = ({
-    name
+    associate,
+    metadata,
+    name,
+    owner,
+    years
   }: {
+    associate?: boolean;
+    metadata?: Metadata;
     name: string;
+    owner?: string;
+    years?: number;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -72,7 +128,83 @@ export class NameMinterMessageComposer implements NameMinterMessage {
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
           mint_and_list: {
-            name
+            associate,
+            metadata,
+            name,
+            owner,
+            years
+          }
+        })),
+        funds
+      })
+    };
+  };
+  batchMintAndList = ({
+    names
+  }: {
+    names: string[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          batch_mint_and_list: {
+            names
+          }
+        })),
+        funds
+      })
+    };
+  };
+  commitMint = ({
+    commitment
+  }: {
+    commitment: Binary;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          commit_mint: {
+            commitment
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revealMint = ({
+    associate,
+    metadata,
+    name,
+    owner,
+    salt,
+    years
+  }: {
+    associate?: boolean;
+    metadata?: Metadata;
+    name: string;
+    owner?: string;
+    salt: string;
+    years?: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reveal_mint: {
+            associate,
+            metadata,
+            name,
+            owner,
+            salt,
+            years
           }
         })),
         funds
@@ -177,4 +309,42 @@ export class NameMinterMessageComposer implements NameMinterMessage {
       })
     };
   };
+  addReservedNames = ({
+    names
+  }: {
+    names: ReservedName[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_reserved_names: {
+            names
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removeReservedNames = ({
+    names
+  }: {
+    names: string[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_reserved_names: {
+            names
+          }
+        })),
+        funds
+      })
+    };
+  };
 }
\ No newline at end of file
@@ -18,7 +18,28 @@ export interface InstantiateMsg {
 }
 export type ExecuteMsg = {
   mint_and_list: {
+    associate?: boolean;
+    metadata?: Metadata | null;
     name: string;
+    owner?: string | null;
+    years?: number | null;
+  };
+} | {
+  batch_mint_and_list: {
+    names: string[];
+  };
+} | {
+  commit_mint: {
+    commitment: Binary;
+  };
+} | {
+  reveal_mint: {
+    associate?: boolean;
+    metadata?: Metadata | null;
+    name: string;
+    owner?: string | null;
+    salt: string;
+    years?: number | null;
   };
 } | {
   update_admin: {
@@ -41,12 +62,58 @@ export type ExecuteMsg = {
   update_config: {
     config: Config;
   };
+} | {
+  add_reserved_names: {
+    names: ReservedName[];
+  };
+} | {
+  remove_reserved_names: {
+    names: string[];
+  };
 };
+export type ContentCodec = "ipfs" | "ipns" | "swarm" | "arweave";
+export type Binary = string;
+export type Addr = string;
+export type KeyAlgo = "x25519" | "secp256k1";
 export type Timestamp = Uint64;
 export type Uint64 = string;
+export interface Metadata {
+  addresses?: ChainAddress[];
+  content_hash?: ContentHash | null;
+  image_nft?: NFT | null;
+  pub_keys?: PubKey[];
+  records: TextRecord[];
+}
+export interface ChainAddress {
+  address: string;
+  chain: string;
+}
+export interface ContentHash {
+  codec: ContentCodec;
+  hash: Binary;
+}
+export interface NFT {
+  collection: Addr;
+  token_id: string;
+}
+export interface PubKey {
+  algo: KeyAlgo;
+  key: Binary;
+}
+export interface TextRecord {
+  name: string;
+  value: string;
+  verified?: boolean | null;
+  verified_at?: Timestamp | null;
+}
 export interface Config {
   public_mint_start_time: Timestamp;
 }
+export interface ReservedName {
+  name: string;
+  owner?: string | null;
+  price?: Uint128 | null;
+}
 export type QueryMsg = {
   admin: {};
 } | {
@@ -57,16 +124,47 @@ export type QueryMsg = {
   params: {};
 } | {
   config: {};
+} | {
+  commit_reveal_params: {};
+} | {
+  commitment: {
+    commitment: Binary;
+  };
+} | {
+  reserved_names: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
 };
 export interface AdminResponse {
   admin?: string | null;
 }
-export type Addr = string;
+export interface CommitRevealParams {
+  max_age: number;
+  min_age: number;
+  required: boolean;
+}
+export type NullableCommitment = Commitment | null;
+export interface Commitment {
+  created_at: Timestamp;
+  owner: Addr;
+}
 export type Decimal = string;
 export interface SudoParams {
   base_price: Uint128;
   fair_burn_percent: Decimal;
   max_name_length: number;
+  max_years?: number | null;
   min_name_length: number;
+  multi_year_discounts?: MultiYearDiscount[];
+}
+export interface MultiYearDiscount {
+  discount: Decimal;
+  min_years: number;
+}
+export type ArrayOfTupleOfStringAndReservation = [string, Reservation][];
+export interface Reservation {
+  owner?: Addr | null;
+  price?: Uint128 | null;
 }
 export type ArrayOfAddr = Addr[];
\ No newline at end of file
@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { Decimal, Timestamp, Uint64, InstantiateMsg, CollectionInfoForRoyaltyInfoResponse, RoyaltyInfoResponse, ExecuteMsg, Addr, Binary, Expiration, NFT, TextRecord, MintMsgForMetadata, Metadata, UpdateCollectionInfoMsgForRoyaltyInfoResponse, QueryMsg, AllNftInfoResponseForMetadata, OwnerOfResponse, Approval, NftInfoResponseForMetadata, OperatorsResponse, TokensResponse, ApprovalResponse, ApprovalsResponse, CollectionInfoResponse, ContractInfoResponse, NullableNFT, Boolean, MinterResponse, String, NumTokensResponse, SudoParams, ArrayOfTextRecord, NullableString } from "./Sg721Name.types";
+import { Decimal, Timestamp, Uint64, InstantiateMsg, CollectionInfoForRoyaltyInfoResponse, RoyaltyInfoResponse, ExecuteMsg, Addr, ContentCodec, Binary, KeyAlgo, RecordOp, Expiration, NFT, ContentHash, PubKey, TextRecord, ChainAddress, ChainAddressProof, MintMsgForMetadata, Metadata, UpdateCollectionInfoMsgForRoyaltyInfoResponse, QueryMsg, String, NullableString, AllNftInfoResponseForMetadata, OwnerOfResponse, Approval, NftInfoResponseForMetadata, OperatorsResponse, TokensResponse, ApprovalResponse, ApprovalsResponse, ArrayOfTupleOfStringAndNullable_String, ArrayOfAssociationResponse, AssociationResponse, ArrayOfAttestor, KeyType, Attestor, CollectionInfoResponse, NullableContentHash, ContractInfoResponse, Uint32, NullableNFT, ImageNftStatusResponse, Boolean, ArrayOfManagerResponse, ManagerResponse, MinterResponse, NumTokensResponse, SudoParams, ArrayOfPubKey, ArrayOfString, NullableTextRecord, ArrayOfTextRecord, ArrayOfVerifierResponse, VerifierResponse } from "./Sg721Name.types";
 export interface Sg721NameReadOnlyInterface {
   contractAddress: string;
   params: () => Promise<SudoParams>;
@@ -15,28 +15,134 @@ export interface Sg721NameReadOnlyInterface {
   }: {
     address: string;
   }) => Promise<String>;
+  names: ({
+    addresses
+  }: {
+    addresses: string[];
+  }) => Promise<ArrayOfTupleOfStringAndNullableString>;
   nameMarketplace: () => Promise<Addr>;
   associatedAddress: ({
     name
   }: {
     name: string;
   }) => Promise<Addr>;
+  associatedAddresses: ({
+    names
+  }: {
+    names: string[];
+  }) => Promise<ArrayOfTupleOfStringAndNullableString>;
+  primaryName: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<NullableString>;
+  addressFor: ({
+    chain,
+    name
+  }: {
+    chain: string;
+    name: string;
+  }) => Promise<String>;
   imageNFT: ({
     name
   }: {
     name: string;
   }) => Promise<NullableNFT>;
-  textRecords: ({
+  contentHash: ({
     name
   }: {
     name: string;
-  }) => Promise<ArrayOfTextRecord>;
-  isTwitterVerified: ({
+  }) => Promise<NullableContentHash>;
+  associationHistory: ({
+    limit,
+    name,
+    startAfter
+  }: {
+    limit?: number;
+    name: string;
+    startAfter?: number;
+  }) => Promise<ArrayOfAssociationResponse>;
+  nameAt: ({
+    address,
+    height
+  }: {
+    address: string;
+    height: number;
+  }) => Promise<NullableString>;
+  aliasOf: ({
     name
   }: {
     name: string;
+  }) => Promise<NullableString>;
+  fuses: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<Uint32>;
+  pubKeys: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<ArrayOfPubKey>;
+  imageNFTStatus: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<ImageNftStatusResponse>;
+  textRecords: ({
+    limit,
+    name,
+    startAfter,
+    verifiedOnly
+  }: {
+    limit?: number;
+    name: string;
+    startAfter?: string;
+    verifiedOnly?: boolean;
+  }) => Promise<ArrayOfTextRecord>;
+  textRecord: ({
+    name,
+    recordName
+  }: {
+    name: string;
+    recordName: string;
+  }) => Promise<NullableTextRecord>;
+  isVerified: ({
+    name,
+    recordName
+  }: {
+    name: string;
+    recordName: string;
   }) => Promise<Boolean>;
-  verifier: () => Promise<NullableString>;
+  nameByRecord: ({
+    recordName,
+    value,
+    verifiedOnly
+  }: {
+    recordName: string;
+    value: string;
+    verifiedOnly: boolean;
+  }) => Promise<String>;
+  verifiers: () => Promise<ArrayOfVerifierResponse>;
+  attestors: ({
+    recordName
+  }: {
+    recordName: string;
+  }) => Promise<ArrayOfAttestor>;
+  managers: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<ArrayOfManagerResponse>;
+  subnames: ({
+    limit,
+    parent,
+    startAfter
+  }: {
+    limit?: number;
+    parent: string;
+    startAfter?: string;
+  }) => Promise<ArrayOfString>;
   ownerOf: ({
     includeExpired,
     tokenId
@@ -113,12 +219,28 @@ export class Sg721NameQueryClient implements Sg721NameReadOnlyInterface {
     this.contractAddress = contractAddress;
     this.params = this.params.bind(this);
     this.name = this.name.bind(this);
+    this.names = this.names.bind(this);
     this.nameMarketplace = this.nameMarketplace.bind(this);
     this.associatedAddress = this.associatedAddress.bind(this);
+    this.associatedAddresses = this.associatedAddresses.bind(this);
+    this.primaryName = this.primaryName.bind(this);
+    this.addressFor = this.addressFor.bind(this);
     this.imageNFT = this.imageNFT.bind(this);
+    this.contentHash = this.contentHash.bind(this);
+    this.associationHistory = this.associationHistory.bind(this);
+    this.nameAt = this.nameAt.bind(this);
+    this.aliasOf = this.aliasOf.bind(this);
+    this.fuses = this.fuses.bind(this);
+    this.pubKeys = this.pubKeys.bind(this);
+    this.imageNFTStatus = this.imageNFTStatus.bind(this);
     this.textRecords = this.textRecords.bind(this);
-    this.isTwitterVerified = this.isTwitterVerified.bind(this);
-    this.verifier = this.verifier.bind(this);
+    this.textRecord = this.textRecord.bind(this);
+    this.isVerified = this.isVerified.bind(this);
+    this.nameByRecord = this.nameByRecord.bind(this);
+    this.verifiers = this.verifiers.bind(this);
+    this.attestors = this.attestors.bind(this);
+    this.managers = this.managers.bind(this);
+    this.subnames = this.subnames.bind(this);
     this.ownerOf = this.ownerOf.bind(this);
     this.approval = this.approval.bind(this);
     this.approvals = this.approvals.bind(this);
@@ -149,6 +271,17 @@ export class Sg721NameQueryClient implements Sg721NameReadOnlyInterface {
       }
     });
   };
+  names = async ({
+    addresses
+  }: {
+    addresses: string[];
+  }): Promise<ArrayOfTupleOfStringAndNullableString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      names: {
+        addresses
+      }
+    });
+  };
   nameMarketplace = async (): Promise<Addr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       name_marketplace: {}
@@ -165,6 +298,42 @@ export class Sg721NameQueryClient implements Sg721NameReadOnlyInterface {
       }
     });
   };
+  associatedAddresses = async ({
+    names
+  }: {
+    names: string[];
+  }): Promise<ArrayOfTupleOfStringAndNullableString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      associated_addresses: {
+        names
+      }
+    });
+  };
+  primaryName = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<NullableString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      primary_name: {
+        address
+      }
+    });
+  };
+  addressFor = async ({
+    chain,
+    name
+  }: {
+    chain: string;
+    name: string;
+  }): Promise<String> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      address_for: {
+        chain,
+        name
+      }
+    });
+  };
   imageNFT = async ({
     name
   }: {
@@ -176,31 +345,199 @@ export class Sg721NameQueryClient implements Sg721NameReadOnlyInterface {
       }
     });
   };
-  textRecords = async ({
+  contentHash = async ({
     name
   }: {
     name: string;
-  }): Promise<ArrayOfTextRecord> => {
+  }): Promise<NullableContentHash> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      text_records: {
+      content_hash: {
+        name
+      }
+    });
+  };
+  associationHistory = async ({
+    limit,
+    name,
+    startAfter
+  }: {
+    limit?: number;
+    name: string;
+    startAfter?: number;
+  }): Promise<ArrayOfAssociationResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      association_history: {
+        limit,
+        name,
+        start_after: startAfter
+      }
+    });
+  };
+  nameAt = async ({
+    address,
+    height
+  }: {
+    address: string;
+    height: number;
+  }): Promise<NullableString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      name_at: {
+        address,
+        height
+      }
+    });
+  };
+  aliasOf = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<NullableString> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      alias_of: {
+        name
+      }
+    });
+  };
+  fuses = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<Uint32> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      fuses: {
+        name
+      }
+    });
+  };
+  pubKeys = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<ArrayOfPubKey> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pub_keys: {
         name
       }
     });
   };
-  isTwitterVerified = async ({
+  imageNFTStatus = async ({
     name
   }: {
     name: string;
+  }): Promise<ImageNftStatusResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      image_n_f_t_status: {
+        name
+      }
+    });
+  };
+  textRecords = async ({
+    limit,
+    name,
+    startAfter,
+    verifiedOnly
+  }: {
+    limit?: number;
+    name: string;
+    startAfter?: string;
+    verifiedOnly?: boolean;
+  }): Promise<ArrayOfTextRecord> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      text_records: {
+        limit,
+        name,
+        start_after: startAfter,
+        verified_only: verifiedOnly
+      }
+    });
+  };
+  textRecord = async ({
+    name,
+    recordName
+  }: {
+    name: string;
+    recordName: string;
+  }): Promise<NullableTextRecord> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      text_record: {
+        name,
+        record_name: recordName
+      }
+    });
+  };
+  isVerified = async ({
+    name,
+    recordName
+  }: {
+    name: string;
+    recordName: string;
   }): Promise<Boolean> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      is_twitter_verified: {
+      is_verified: {
+        name,
+        record_name: recordName
+      }
+    });
+  };
+  nameByRecord = async ({
+    recordName,
+    value,
+    verifiedOnly
+  }: {
+    recordName: string;
+    value: string;
+    verifiedOnly: boolean;
+  }): Promise<String> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      name_by_record: {
+        record_name: recordName,
+        value,
+        verified_only: verifiedOnly
+      }
+    });
+  };
+  verifiers = async (): Promise<ArrayOfVerifierResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      verifiers: {}
+    });
+  };
+  attestors = async ({
+    recordName
+  }: {
+    recordName: string;
+  }): Promise<ArrayOfAttestor> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      attestors: {
+        record_name: recordName
+      }
+    });
+  };
+  managers = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<ArrayOfManagerResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      managers: {
         name
       }
     });
   };
-  verifier = async (): Promise<NullableString> => {
+  subnames = async ({
+    limit,
+    parent,
+    startAfter
+  }: {
+    limit?: number;
+    parent: string;
+    startAfter?: string;
+  }): Promise<ArrayOfString> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      verifier: {}
+      subnames: {
+        limit,
+        parent,
+        start_after: startAfter
+      }
     });
   };
   ownerOf = async ({
@@ -353,6 +690,11 @@ export interface Sg721NameInterface extends Sg721NameReadOnlyInterface {
   }: {
     address: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  migrateRecords: ({
+    limit
+  }: {
+    limit?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   associateAddress: ({
     address,
     name
@@ -367,6 +709,45 @@ export interface Sg721NameInterface extends Sg721NameReadOnlyInterface {
     name: string;
     nft?: NFT;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateContentHash: ({
+    contentHash,
+    name
+  }: {
+    contentHash?: ContentHash;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setPubKey: ({
+    name,
+    pubKey,
+    signature,
+    signer
+  }: {
+    name: string;
+    pubKey: PubKey;
+    signature: Binary;
+    signer: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removePubKey: ({
+    algo,
+    name
+  }: {
+    algo: KeyAlgo;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setAlias: ({
+    name,
+    target
+  }: {
+    name: string;
+    target?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  burnFuses: ({
+    fuses,
+    name
+  }: {
+    fuses: number;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   addTextRecord: ({
     name,
     record
@@ -388,6 +769,13 @@ export interface Sg721NameInterface extends Sg721NameReadOnlyInterface {
     name: string;
     record: TextRecord;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  batchUpdate: ({
+    name,
+    ops
+  }: {
+    name: string;
+    ops: RecordOp[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   verifyTextRecord: ({
     name,
     recordName,
@@ -397,10 +785,113 @@ export interface Sg721NameInterface extends Sg721NameReadOnlyInterface {
     recordName: string;
     result: boolean;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  updateVerifier: ({
+  requestVerification: ({
+    name,
+    recordName
+  }: {
+    name: string;
+    recordName: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  verifyTextRecordWithSignature: ({
+    issuedAt,
+    name,
+    pubKey,
+    record,
+    signature
+  }: {
+    issuedAt: Timestamp;
+    name: string;
+    pubKey: Binary;
+    record: TextRecord;
+    signature: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addVerifier: ({
+    scope,
     verifier
   }: {
-    verifier?: string;
+    scope: string;
+    verifier: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeVerifier: ({
+    scope,
+    verifier
+  }: {
+    scope: string;
+    verifier: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  transferWithMetadata: ({
+    recipient,
+    tokenId
+  }: {
+    recipient: string;
+    tokenId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setPrimaryName: ({
+    name
+  }: {
+    name?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addChainAddress: ({
+    address,
+    name,
+    proof
+  }: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeChainAddress: ({
+    chain,
+    name
+  }: {
+    chain: string;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateChainAddress: ({
+    address,
+    name,
+    proof
+  }: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addManager: ({
+    expires,
+    manager,
+    name
+  }: {
+    expires?: Expiration;
+    manager: string;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeManager: ({
+    manager,
+    name
+  }: {
+    manager: string;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  createSubname: ({
+    label,
+    owner,
+    parent
+  }: {
+    label: string;
+    owner: string;
+    parent: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  transferSubname: ({
+    name,
+    recipient
+  }: {
+    name: string;
+    recipient: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeSubname: ({
+    name
+  }: {
+    name: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   transferNft: ({
     recipient,
@@ -481,13 +972,33 @@ export class Sg721NameClient extends Sg721NameQueryClient implements Sg721NameIn
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.setNameMarketplace = this.setNameMarketplace.bind(this);
+    this.migrateRecords = this.migrateRecords.bind(this);
     this.associateAddress = this.associateAddress.bind(this);
     this.updateImageNft = this.updateImageNft.bind(this);
+    this.updateContentHash = this.updateContentHash.bind(this);
+    this.setPubKey = this.setPubKey.bind(this);
+    this.removePubKey = this.removePubKey.bind(this);
+    this.setAlias = this.setAlias.bind(this);
+    this.burnFuses = this.burnFuses.bind(this);
     this.addTextRecord = this.addTextRecord.bind(this);
     this.removeTextRecord = this.removeTextRecord.bind(this);
     this.updateTextRecord = this.updateTextRecord.bind(this);
+    this.batchUpdate = this.batchUpdate.bind(this);
     this.verifyTextRecord = this.verifyTextRecord.bind(this);
-    this.updateVerifier = this.updateVerifier.bind(this);
+    this.requestVerification = this.requestVerification.bind(this);
+    this.verifyTextRecordWithSignature = this.verifyTextRecordWithSignature.bind(this);
+    this.addVerifier = this.addVerifier.bind(this);
+    this.removeVerifier = this.removeVerifier.bind(this);
+    this.transferWithMetadata = this.transferWithMetadata.bind(this);
+    this.setPrimaryName = this.setPrimaryName.bind(this);
+    this.addChainAddress = this.addChainAddress.bind(this);
+    this.removeChainAddress = this.removeChainAddress.bind(this);
+    this.updateChainAddress = this.updateChainAddress.bind(this);
+    this.addManager = this.addManager.bind(this);
+    this.removeManager = this.removeManager.bind(this);
+    this.createSubname = this.createSubname.bind(this);
+    this.transferSubname = this.transferSubname.bind(this);
+    this.revokeSubname = this.revokeSubname.bind(this);
     this.transferNft = this.transferNft.bind(this);
     this.sendNft = this.sendNft.bind(this);
     this.approve = this.approve.bind(this);
@@ -512,6 +1023,17 @@ export class Sg721NameClient extends Sg721NameQueryClient implements Sg721NameIn
       }
     }, fee, memo, funds);
   };
+  migrateRecords = async ({
+    limit
+  }: {
+    limit?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      migrate_records: {
+        limit
+      }
+    }, fee, memo, funds);
+  };
   associateAddress = async ({
     address,
     name
@@ -540,6 +1062,82 @@ export class Sg721NameClient extends Sg721NameQueryClient implements Sg721NameIn
       }
     }, fee, memo, funds);
   };
+  updateContentHash = async ({
+    contentHash,
+    name
+  }: {
+    contentHash?: ContentHash;
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_content_hash: {
+        content_hash: contentHash,
+        name
+      }
+    }, fee, memo, funds);
+  };
+  setPubKey = async ({
+    name,
+    pubKey,
+    signature,
+    signer
+  }: {
+    name: string;
+    pubKey: PubKey;
+    signature: Binary;
+    signer: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_pub_key: {
+        name,
+        pub_key: pubKey,
+        signature,
+        signer
+      }
+    }, fee, memo, funds);
+  };
+  removePubKey = async ({
+    algo,
+    name
+  }: {
+    algo: KeyAlgo;
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_pub_key: {
+        algo,
+        name
+      }
+    }, fee, memo, funds);
+  };
+  setAlias = async ({
+    name,
+    target
+  }: {
+    name: string;
+    target?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_alias: {
+        name,
+        target
+      }
+    }, fee, memo, funds);
+  };
+  burnFuses = async ({
+    fuses,
+    name
+  }: {
+    fuses: number;
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      burn_fuses: {
+        fuses,
+        name
+      }
+    }, fee, memo, funds);
+  };
   addTextRecord = async ({
     name,
     record
@@ -582,6 +1180,20 @@ export class Sg721NameClient extends Sg721NameQueryClient implements Sg721NameIn
       }
     }, fee, memo, funds);
   };
+  batchUpdate = async ({
+    name,
+    ops
+  }: {
+    name: string;
+    ops: RecordOp[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      batch_update: {
+        name,
+        ops
+      }
+    }, fee, memo, funds);
+  };
   verifyTextRecord = async ({
     name,
     recordName,
@@ -599,17 +1211,217 @@ export class Sg721NameClient extends Sg721NameQueryClient implements Sg721NameIn
       }
     }, fee, memo, funds);
   };
-  updateVerifier = async ({
+  requestVerification = async ({
+    name,
+    recordName
+  }: {
+    name: string;
+    recordName: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      request_verification: {
+        name,
+        record_name: recordName
+      }
+    }, fee, memo, funds);
+  };
+  verifyTextRecordWithSignature = async ({
+    issuedAt,
+    name,
+    pubKey,
+    record,
+    signature
+  }: {
+    issuedAt: Timestamp;
+    name: string;
+    pubKey: Binary;
+    record: TextRecord;
+    signature: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      verify_text_record_with_signature: {
+        issued_at: issuedAt,
+        name,
+        pub_key: pubKey,
+        record,
+        signature
+      }
+    }, fee, memo, funds);
+  };
+  addVerifier = async ({
+    scope,
     verifier
   }: {
-    verifier?: string;
+    scope: string;
+    verifier: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      update_verifier: {
+      add_verifier: {
+        scope,
         verifier
       }
     }, fee, memo, funds);
   };
+  removeVerifier = async ({
+    scope,
+    verifier
+  }: {
+    scope: string;
+    verifier: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_verifier: {
+        scope,
+        verifier
+      }
+    }, fee, memo, funds);
+  };
+  transferWithMetadata = async ({
+    recipient,
+    tokenId
+  }: {
+    recipient: string;
+    tokenId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      transfer_with_metadata: {
+        recipient,
+        token_id: tokenId
+      }
+    }, fee, memo, funds);
+  };
+  setPrimaryName = async ({
+    name
+  }: {
+    name?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_primary_name: {
+        name
+      }
+    }, fee, memo, funds);
+  };
+  addChainAddress = async ({
+    address,
+    name,
+    proof
+  }: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_chain_address: {
+        address,
+        name,
+        proof
+      }
+    }, fee, memo, funds);
+  };
+  removeChainAddress = async ({
+    chain,
+    name
+  }: {
+    chain: string;
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_chain_address: {
+        chain,
+        name
+      }
+    }, fee, memo, funds);
+  };
+  updateChainAddress = async ({
+    address,
+    name,
+    proof
+  }: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_chain_address: {
+        address,
+        name,
+        proof
+      }
+    }, fee, memo, funds);
+  };
+  addManager = async ({
+    expires,
+    manager,
+    name
+  }: {
+    expires?: Expiration;
+    manager: string;
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_manager: {
+        expires,
+        manager,
+        name
+      }
+    }, fee, memo, funds);
+  };
+  removeManager = async ({
+    manager,
+    name
+  }: {
+    manager: string;
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_manager: {
+        manager,
+        name
+      }
+    }, fee, memo, funds);
+  };
+  createSubname = async ({
+    label,
+    owner,
+    parent
+  }: {
+    label: string;
+    owner: string;
+    parent: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      create_subname: {
+        label,
+        owner,
+        parent
+      }
+    }, fee, memo, funds);
+  };
+  transferSubname = async ({
+    name,
+    recipient
+  }: {
+    name: string;
+    recipient: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      transfer_subname: {
+        name,
+        recipient
+      }
+    }, fee, memo, funds);
+  };
+  revokeSubname = async ({
+    name
+  }: {
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_subname: {
+        name
+      }
+    }, fee, memo, funds);
+  };
   transferNft = async ({
     recipient,
     tokenId
@@ -8,7 +8,7 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "cosmwasm";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { Decimal, Timestamp, Uint64, InstantiateMsg, CollectionInfoForRoyaltyInfoResponse, RoyaltyInfoResponse, ExecuteMsg, Addr, Binary, Expiration, NFT, TextRecord, MintMsgForMetadata, Metadata, UpdateCollectionInfoMsgForRoyaltyInfoResponse, QueryMsg, AllNftInfoResponseForMetadata, OwnerOfResponse, Approval, NftInfoResponseForMetadata, OperatorsResponse, TokensResponse, ApprovalResponse, ApprovalsResponse, CollectionInfoResponse, ContractInfoResponse, NullableNFT, Boolean, MinterResponse, String, NumTokensResponse, SudoParams, ArrayOfTextRecord, NullableString } from "./Sg721Name.types";
+import { Decimal, Timestamp, Uint64, InstantiateMsg, CollectionInfoForRoyaltyInfoResponse, RoyaltyInfoResponse, ExecuteMsg, Addr, ContentCodec, Binary, KeyAlgo, RecordOp, Expiration, NFT, ContentHash, PubKey, TextRecord, ChainAddress, ChainAddressProof, MintMsgForMetadata, Metadata, UpdateCollectionInfoMsgForRoyaltyInfoResponse, QueryMsg, String, NullableString, AllNftInfoResponseForMetadata, OwnerOfResponse, Approval, NftInfoResponseForMetadata, OperatorsResponse, TokensResponse, ApprovalResponse, ApprovalsResponse, ArrayOfTupleOfStringAndNullable_String, ArrayOfAssociationResponse, AssociationResponse, ArrayOfAttestor, KeyType, Attestor, CollectionInfoResponse, NullableContentHash, ContractInfoResponse, Uint32, NullableNFT, ImageNftStatusResponse, Boolean, ArrayOfManagerResponse, ManagerResponse, MinterResponse, NumTokensResponse, SudoParams, ArrayOfPubKey, ArrayOfString, NullableTextRecord, ArrayOfTextRecord, ArrayOfVerifierResponse, VerifierResponse } from "./Sg721Name.types";
 export interface Sg721NameMessage {
   contractAddress: string;
   sender: string;
@@ -17,6 +17,11 @@ export interface Sg721NameMessage {
   }: {
     address: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  migrateRecords: ({
+    limit
+  }: {
+    limit?: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   associateAddress: ({
     address,
     name
@@ -31,6 +36,45 @@ export interface Sg721NameMessage {
     name: string;
     nft?: NFT;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateContentHash: ({
+    contentHash,
+    name
+  }: {
+    contentHash?: ContentHash;
+    name: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setPubKey: ({
+    name,
+    pubKey,
+    signature,
+    signer
+  }: {
+    name: string;
+    pubKey: PubKey;
+    signature: Binary;
+    signer: Binary;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removePubKey: ({
+    algo,
+    name
+  }: {
+    algo: KeyAlgo;
+    name: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setAlias: ({
+    name,
+    target
+  }: {
+    name: string;
+    target?: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  burnFuses: ({
+    fuses,
+    name
+  }: {
+    fuses: number;
+    name: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   addTextRecord: ({
     name,
     record
@@ -52,6 +96,13 @@ export interface Sg721NameMessage {
     name: string;
     record: TextRecord;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  batchUpdate: ({
+    name,
+    ops
+  }: {
+    name: string;
+    ops: RecordOp[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   verifyTextRecord: ({
     name,
     recordName,
@@ -61,10 +112,113 @@ export interface Sg721NameMessage {
     recordName: string;
     result: boolean;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
-  updateVerifier: ({
+  requestVerification: ({
+    name,
+    recordName
+  }: {
+    name: string;
+    recordName: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  verifyTextRecordWithSignature: ({
+    issuedAt,
+    name,
+    pubKey,
+    record,
+    signature
+  }: {
+    issuedAt: Timestamp;
+    name: string;
+    pubKey: Binary;
+    record: TextRecord;
+    signature: Binary;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addVerifier: ({
+    scope,
     verifier
   }: {
-    verifier?: string;
+    scope: string;
+    verifier: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeVerifier: ({
+    scope,
+    verifier
+  }: {
+    scope: string;
+    verifier: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  transferWithMetadata: ({
+    recipient,
+    tokenId
+  }: {
+    recipient: string;
+    tokenId: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setPrimaryName: ({
+    name
+  }: {
+    name?: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addChainAddress: ({
+    address,
+    name,
+    proof
+  }: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeChainAddress: ({
+    chain,
+    name
+  }: {
+    chain: string;
+    name: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateChainAddress: ({
+    address,
+    name,
+    proof
+  }: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  addManager: ({
+    expires,
+    manager,
+    name
+  }: {
+    expires?: Expiration;
+    manager: string;
+    name: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  removeManager: ({
+    manager,
+    name
+  }: {
+    manager: string;
+    name: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  createSubname: ({
+    label,
+    owner,
+    parent
+  }: {
+    label: string;
+    owner: string;
+    parent: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  transferSubname: ({
+    name,
+    recipient
+  }: {
+    name: string;
+    recipient: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeSubname: ({
+    name
+  }: {
+    name: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   transferNft: ({
     recipient,
@@ -142,13 +296,33 @@ export class Sg721NameMessageComposer implements Sg721NameMessage {
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.setNameMarketplace = this.setNameMarketplace.bind(this);
+    this.migrateRecords = this.migrateRecords.bind(this);
     this.associateAddress = this.associateAddress.bind(this);
     this.updateImageNft = this.updateImageNft.bind(this);
+    this.updateContentHash = this.updateContentHash.bind(this);
+    this.setPubKey = this.setPubKey.bind(this);
+    this.removePubKey = this.removePubKey.bind(this);
+    this.setAlias = this.setAlias.bind(this);
+    this.burnFuses = this.burnFuses.bind(this);
     this.addTextRecord = this.addTextRecord.bind(this);
     this.removeTextRecord = this.removeTextRecord.bind(this);
     this.updateTextRecord = this.updateTextRecord.bind(this);
+    this.batchUpdate = this.batchUpdate.bind(this);
     this.verifyTextRecord = this.verifyTextRecord.bind(this);
-    this.updateVerifier = this.updateVerifier.bind(this);
+    this.requestVerification = this.requestVerification.bind(this);
+    this.verifyTextRecordWithSignature = this.verifyTextRecordWithSignature.bind(this);
+    this.addVerifier = this.addVerifier.bind(this);
+    this.removeVerifier = this.removeVerifier.bind(this);
+    this.transferWithMetadata = this.transferWithMetadata.bind(this);
+    this.setPrimaryName = this.setPrimaryName.bind(this);
+    this.addChainAddress = this.addChainAddress.bind(this);
+    this.removeChainAddress = this.removeChainAddress.bind(this);
+    this.updateChainAddress = this.updateChainAddress.bind(this);
+    this.addManager = this.addManager.bind(this);
+    this.removeManager = this.removeManager.bind(this);
+    this.createSubname = this.createSubname.bind(this);
+    this.transferSubname = this.transferSubname.bind(this);
+    this.revokeSubname = this.revokeSubname.bind(this);
     this.transferNft = this.transferNft.bind(this);
     this.sendNft = this.sendNft.bind(this);
     this.approve = this.approve.bind(this);
@@ -181,6 +355,25 @@ export class Sg721NameMessageComposer implements Sg721NameMessage {
       })
     };
   };
+  migrateRecords = ({
+    limit
+  }: {
+    limit?: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          migrate_records: {
+            limit
+          }
+        })),
+        funds
+      })
+    };
+  };
   associateAddress = ({
     address,
     name
@@ -225,6 +418,122 @@ export class Sg721NameMessageComposer implements Sg721NameMessage {
       })
     };
   };
+  updateContentHash = ({
+    contentHash,
+    name
+  }: {
+    contentHash?: ContentHash;
+    name: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_content_hash: {
+            content_hash: contentHash,
+            name
+          }
+        })),
+        funds
+      })
+    };
+  };
+  setPubKey = ({
+    name,
+    pubKey,
+    signature,
+    signer
+  }: {
+    name: string;
+    pubKey: PubKey;
+    signature: Binary;
+    signer: Binary;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_pub_key: {
+            name,
+            pub_key: pubKey,
+            signature,
+            signer
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removePubKey = ({
+    algo,
+    name
+  }: {
+    algo: KeyAlgo;
+    name: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_pub_key: {
+            algo,
+            name
+          }
+        })),
+        funds
+      })
+    };
+  };
+  setAlias = ({
+    name,
+    target
+  }: {
+    name: string;
+    target?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_alias: {
+            name,
+            target
+          }
+        })),
+        funds
+      })
+    };
+  };
+  burnFuses = ({
+    fuses,
+    name
+  }: {
+    fuses: number;
+    name: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          burn_fuses: {
+            fuses,
+            name
+          }
+        })),
+        funds
+      })
+    };
+  };
   addTextRecord = ({
     name,
     record
@@ -291,6 +600,28 @@ export class Sg721NameMessageComposer implements Sg721NameMessage {
       })
     };
   };
+  batchUpdate = ({
+    name,
+    ops
+  }: {
+    name: string;
+    ops: RecordOp[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          batch_update: {
+            name,
+            ops
+          }
+        })),
+        funds
+      })
+    };
+  };
   verifyTextRecord = ({
     name,
     recordName,
@@ -316,10 +647,65 @@ export class Sg721NameMessageComposer implements Sg721NameMessage {
       })
     };
   };
-  updateVerifier = ({
+  requestVerification = ({
+    name,
+    recordName
+  }: {
+    name: string;
+    recordName: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          request_verification: {
+            name,
+            record_name: recordName
+          }
+        })),
+        funds
+      })
+    };
+  };
+  verifyTextRecordWithSignature = ({
+    issuedAt,
+    name,
+    pubKey,
+    record,
+    signature
+  }: {
+    issuedAt: Timestamp;
+    name: string;
+    pubKey: Binary;
+    record: TextRecord;
+    signature: Binary;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          verify_text_record_with_signature: {
+            issued_at: issuedAt,
+            name,
+            pub_key: pubKey,
+            record,
+            signature
+          }
+        })),
+        funds
+      })
+    };
+  };
+  addVerifier = ({
+    scope,
     verifier
   }: {
-    verifier?: string;
+    scope: string;
+    verifier: string;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
@@ -327,7 +713,8 @@ export class Sg721NameMessageComposer implements Sg721NameMessage {
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          update_verifier: {
+          add_verifier: {
+            scope,
             verifier
           }
         })),
@@ -335,6 +722,254 @@ export class Sg721NameMessageComposer implements Sg721NameMessage {
       })
     };
   };
+  removeVerifier = ({
+    scope,
+    verifier
+  }: {
+    scope: string;
+    verifier: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_verifier: {
+            scope,
+            verifier
+          }
+        })),
+        funds
+      })
+    };
+  };
+  transferWithMetadata = ({
+    recipient,
+    tokenId
+  }: {
+    recipient: string;
+    tokenId: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          transfer_with_metadata: {
+            recipient,
+            token_id: tokenId
+          }
+        })),
+        funds
+      })
+    };
+  };
+  setPrimaryName = ({
+    name
+  }: {
+    name?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_primary_name: {
+            name
+          }
+        })),
+        funds
+      })
+    };
+  };
+  addChainAddress = ({
+    address,
+    name,
+    proof
+  }: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_chain_address: {
+            address,
+            name,
+            proof
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removeChainAddress = ({
+    chain,
+    name
+  }: {
+    chain: string;
+    name: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_chain_address: {
+            chain,
+            name
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateChainAddress = ({
+    address,
+    name,
+    proof
+  }: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_chain_address: {
+            address,
+            name,
+            proof
+          }
+        })),
+        funds
+      })
+    };
+  };
+  addManager = ({
+    expires,
+    manager,
+    name
+  }: {
+    expires?: Expiration;
+    manager: string;
+    name: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          add_manager: {
+            expires,
+            manager,
+            name
+          }
+        })),
+        funds
+      })
+    };
+  };
+  removeManager = ({
+    manager,
+    name
+  }: {
+    manager: string;
+    name: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          remove_manager: {
+            manager,
+            name
+          }
+        })),
+        funds
+      })
+    };
+  };
+  createSubname = ({
+    label,
+    owner,
+    parent
+  }: {
+    label: string;
+    owner: string;
+    parent: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          create_subname: {
+            label,
+            owner,
+            parent
+          }
+        })),
+        funds
+      })
+    };
+  };
+  transferSubname = ({
+    name,
+    recipient
+  }: {
+    name: string;
+    recipient: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          transfer_subname: {
+            name,
+            recipient
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revokeSubname = ({
+    name
+  }: {
+    name: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_subname: {
+            name
+          }
+        })),
+        funds
+      })
+    };
+  };
   transferNft = ({
     recipient,
     tokenId
@@ -30,6 +30,10 @@ export type ExecuteMsg = {
   set_name_marketplace: {
     address: string;
   };
+} | {
+  migrate_records: {
+    limit?: number | null;
+  };
 } | {
   associate_address: {
     address?: string | null;
@@ -40,6 +44,33 @@ export type ExecuteMsg = {
     name: string;
     nft?: NFT | null;
   };
+} | {
+  update_content_hash: {
+    content_hash?: ContentHash | null;
+    name: string;
+  };
+} | {
+  set_pub_key: {
+    name: string;
+    pub_key: PubKey;
+    signature: Binary;
+    signer: Binary;
+  };
+} | {
+  remove_pub_key: {
+    algo: KeyAlgo;
+    name: string;
+  };
+} | {
+  set_alias: {
+    name: string;
+    target?: string | null;
+  };
+} | {
+  burn_fuses: {
+    fuses: number;
+    name: string;
+  };
 } | {
   add_text_record: {
     name: string;
@@ -55,6 +86,11 @@ export type ExecuteMsg = {
     name: string;
     record: TextRecord;
   };
+} | {
+  batch_update: {
+    name: string;
+    ops: RecordOp[];
+  };
 } | {
   verify_text_record: {
     name: string;
@@ -62,8 +98,79 @@ export type ExecuteMsg = {
     result: boolean;
   };
 } | {
-  update_verifier: {
-    verifier?: string | null;
+  request_verification: {
+    name: string;
+    record_name: string;
+  };
+} | {
+  verify_text_record_with_signature: {
+    issued_at: Timestamp;
+    name: string;
+    pub_key: Binary;
+    record: TextRecord;
+    signature: Binary;
+  };
+} | {
+  add_verifier: {
+    scope: string;
+    verifier: string;
+  };
+} | {
+  remove_verifier: {
+    scope: string;
+    verifier: string;
+  };
+} | {
+  transfer_with_metadata: {
+    recipient: string;
+    token_id: string;
+  };
+} | {
+  set_primary_name: {
+    name?: string | null;
+  };
+} | {
+  add_chain_address: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof | null;
+  };
+} | {
+  remove_chain_address: {
+    chain: string;
+    name: string;
+  };
+} | {
+  update_chain_address: {
+    address: ChainAddress;
+    name: string;
+    proof?: ChainAddressProof | null;
+  };
+} | {
+  add_manager: {
+    expires?: Expiration | null;
+    manager: string;
+    name: string;
+  };
+} | {
+  remove_manager: {
+    manager: string;
+    name: string;
+  };
+} | {
+  create_subname: {
+    label: string;
+    owner: string;
+    parent: string;
+  };
+} | {
+  transfer_subname: {
+    name: string;
+    recipient: string;
+  };
+} | {
+  revoke_subname: {
+    name: string;
   };
 } | {
   transfer_nft: {
@@ -112,7 +219,30 @@ export type ExecuteMsg = {
   freeze_collection_info: {};
 };
 export type Addr = string;
+export type ContentCodec = "ipfs" | "ipns" | "swarm" | "arweave";
 export type Binary = string;
+export type KeyAlgo = "x25519" | "secp256k1";
+export type RecordOp = {
+  add_text_record: {
+    record: TextRecord;
+  };
+} | {
+  update_text_record: {
+    record: TextRecord;
+  };
+} | {
+  remove_text_record: {
+    record_name: string;
+  };
+} | {
+  update_image_nft: {
+    nft?: NFT | null;
+  };
+} | {
+  associate_address: {
+    address?: string | null;
+  };
+};
 export type Expiration = {
   at_height: number;
 } | {
@@ -124,10 +254,27 @@ export interface NFT {
   collection: Addr;
   token_id: string;
 }
+export interface ContentHash {
+  codec: ContentCodec;
+  hash: Binary;
+}
+export interface PubKey {
+  algo: KeyAlgo;
+  key: Binary;
+}
 export interface TextRecord {
   name: string;
   value: string;
   verified?: boolean | null;
+  verified_at?: Timestamp | null;
+}
+export interface ChainAddress {
+  address: string;
+  chain: string;
+}
+export interface ChainAddressProof {
+  pub_key: Binary;
+  signature: Binary;
 }
 export interface MintMsgForMetadata {
   extension: Metadata;
@@ -136,7 +283,10 @@ export interface MintMsgForMetadata {
   token_uri?: string | null;
 }
 export interface Metadata {
+  addresses?: ChainAddress[];
+  content_hash?: ContentHash | null;
   image_nft?: NFT | null;
+  pub_keys?: PubKey[];
   records: TextRecord[];
 }
 export interface UpdateCollectionInfoMsgForRoyaltyInfoResponse {
@@ -152,26 +302,103 @@ export type QueryMsg = {
   name: {
     address: string;
   };
+} | {
+  names: {
+    addresses: string[];
+  };
 } | {
   name_marketplace: {};
 } | {
   associated_address: {
     name: string;
   };
+} | {
+  associated_addresses: {
+    names: string[];
+  };
+} | {
+  primary_name: {
+    address: string;
+  };
+} | {
+  address_for: {
+    chain: string;
+    name: string;
+  };
 } | {
   image_n_f_t: {
     name: string;
   };
+} | {
+  content_hash: {
+    name: string;
+  };
+} | {
+  association_history: {
+    limit?: number | null;
+    name: string;
+    start_after?: number | null;
+  };
+} | {
+  name_at: {
+    address: string;
+    height: number;
+  };
+} | {
+  alias_of: {
+    name: string;
+  };
+} | {
+  fuses: {
+    name: string;
+  };
+} | {
+  pub_keys: {
+    name: string;
+  };
+} | {
+  image_n_f_t_status: {
+    name: string;
+  };
 } | {
   text_records: {
+    limit?: number | null;
+    name: string;
+    start_after?: string | null;
+    verified_only?: boolean | null;
+  };
+} | {
+  text_record: {
+    name: string;
+    record_name: string;
+  };
+} | {
+  is_verified: {
     name: string;
+    record_name: string;
   };
 } | {
-  is_twitter_verified: {
+  name_by_record: {
+    record_name: string;
+    value: string;
+    verified_only: boolean;
+  };
+} | {
+  verifiers: {};
+} | {
+  attestors: {
+    record_name: string;
+  };
+} | {
+  managers: {
     name: string;
   };
 } | {
-  verifier: {};
+  subnames: {
+    limit?: number | null;
+    parent: string;
+    start_after?: string | null;
+  };
 } | {
   owner_of: {
     include_expired?: boolean | null;
@@ -224,6 +451,8 @@ export type QueryMsg = {
 } | {
   collection_info: {};
 };
+export type String = string;
+export type NullableString = string | null;
 export interface AllNftInfoResponseForMetadata {
   access: OwnerOfResponse;
   info: NftInfoResponseForMetadata;
@@ -252,6 +481,18 @@ export interface ApprovalResponse {
 export interface ApprovalsResponse {
   approvals: Approval[];
 }
+export type ArrayOfTupleOfStringAndNullable_String = [string, string | null][];
+export type ArrayOfAssociationResponse = AssociationResponse[];
+export interface AssociationResponse {
+  address?: Addr | null;
+  height: number;
+}
+export type ArrayOfAttestor = Attestor[];
+export type KeyType = "secp256k1" | "ed25519";
+export interface Attestor {
+  key_type: KeyType;
+  pub_key: Binary;
+}
 export interface CollectionInfoResponse {
   creator: string;
   description: string;
@@ -261,21 +502,40 @@ export interface CollectionInfoResponse {
   royalty_info?: RoyaltyInfoResponse | null;
   start_trading_time?: Timestamp | null;
 }
+export type NullableContentHash = ContentHash | null;
 export interface ContractInfoResponse {
   name: string;
   symbol: string;
 }
+export type Uint32 = number;
 export type NullableNFT = NFT | null;
+export interface ImageNftStatusResponse {
+  nft?: NFT | null;
+  owned: boolean;
+}
 export type Boolean = boolean;
+export type ArrayOfManagerResponse = ManagerResponse[];
+export interface ManagerResponse {
+  expires: Expiration;
+  manager: Addr;
+}
 export interface MinterResponse {
   minter: string;
 }
-export type String = string;
 export interface NumTokensResponse {
   count: number;
 }
 export interface SudoParams {
+  max_history_count?: number | null;
   max_record_count: number;
+  verification_period?: number | null;
 }
+export type ArrayOfPubKey = PubKey[];
+export type ArrayOfString = string[];
+export type NullableTextRecord = TextRecord | null;
 export type ArrayOfTextRecord = TextRecord[];
-export type NullableString = string | null;
\ No newline at end of file
+export type ArrayOfVerifierResponse = VerifierResponse[];
+export interface VerifierResponse {
+  scope: string;
+  verifier: Addr;
+}
\ No newline at end of file